@@ -1,16 +1,38 @@
 mod parse;
 
+#[cfg(all(feature = "bevy_egui", feature = "egui"))]
+compile_error!(
+	"the `bevy_egui` and `egui` features are mutually exclusive, \
+	 disable the default features to use `egui`"
+);
+
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
 /// # ControlPanel
 ///
 /// Deriving ControlPanel on a struct generates a control
-/// panel pseudo-widget (based on [eframe::egui]) that lets you tweak the fields
+/// panel pseudo-widget (based on [bevy_egui::egui]) that lets you tweak the fields
 /// of the struct in real-time.
 ///
 /// This exposes a method `ui` on the underlying struct that can be passed an
-/// `&mut eframe::egui::Ui` to paint the panel to the UI.
+/// `&mut bevy_egui::egui::Ui` to paint the panel to the UI,
+/// and a companion `show` that does the same but discards the `Response`
+/// for when you only care about the side effects. `ui_changed` returns whether
+/// any field was edited instead, and warns when that is ignored. `ui_framed` paints the
+/// panel inside a given `egui::Frame`, e.g. to box nested panels with their own background,
+/// rounding and margins, while `ui` itself stays frame-less.
+///
+/// On structs, `ui_builder` returns a `<Struct>ControlsBuilder` rendering the rows one at a
+/// time instead, for mixing custom rows in between, e.g.
+/// `settings.ui_builder(ui).field("speed").custom(|ui| { ui.separator(); }).rest()`. `field`
+/// renders the row of the named field, `custom` renders whatever the closure adds, and `rest`
+/// renders every row not rendered yet, returning the union of the widgets' `Response`s. The
+/// builder renders the rows only, without the layout, description or `touch` sizing of the
+/// panel, and never in a grid.
+///
+/// Fields gated by `#[cfg(...)]` are left out before the derive runs, so a field compiled
+/// out has no row, no `control_rect` and no part in the generated methods.
 ///
 /// # Note:
 /// This can be especially useful if you're implementing
@@ -20,7 +42,8 @@ use syn::{parse_macro_input, DeriveInput};
 ///
 /// # Examples
 /// ```no_run
-/// use egui_controls::ControlPanel;
+/// use bevy_egui::egui;
+/// use bevy_egui_controls::ControlPanel;
 ///
 /// #[derive(Debug, Clone, ControlPanel)]
 /// pub struct CirclePackingAlgorithmConfig {
@@ -49,10 +72,10 @@ use syn::{parse_macro_input, DeriveInput};
 ///     settings: CirclePackingAlgorithmConfig
 /// }
 ///
-/// impl eframe::App for MyApp {
-///     fn update(&mut self, ctx: &::eframe::egui::Context, frame: &mut ::eframe::Frame) {
-///         ::eframe::egui::CentralPanel::default().show(ctx, |ui: &mut ::eframe::egui::Ui| {
-///             self.settings.ui(ui);
+/// impl MyApp {
+///     fn update(&mut self, ctx: &egui::Context) {
+///         egui::CentralPanel::default().show(ctx, |ui: &mut egui::Ui| {
+///             self.settings.show(ui);
 ///             ui.vertical(|ui| {
 ///                 ui.code(format!("{:#?}", &self.settings));
 ///             });
@@ -60,7 +83,327 @@ use syn::{parse_macro_input, DeriveInput};
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(ControlPanel, attributes(control))]
+///
+/// # Controls
+/// - `#[control(slider(<range>))]` renders an `egui::Slider` over the given range.
+///   Add `fill` after the range, e.g. `slider(0.0..=1.0, fill)`, to fill the track
+///   up to the handle.
+///   The range is evaluated every frame before the widget, so it can refer to other fields,
+///   e.g. `slider(0.0..=self.max_health)`. A value outside of the range is left alone
+///   unless `clamp` is also passed, e.g. `slider(0.0..=self.max_health, clamp)`,
+///   which clamps it back into the range when the range shrinks.
+///   Add `text = "..."`, e.g. `slider(0.0..=1.0, text = "opacity")`, for the label egui
+///   attaches to the slider itself, after its value. It is independent of the name column
+///   of a grid and of the description after the widget.
+///   A bare `#[control(slider)]` on an integer field spans every value of its type,
+///   e.g. `0..=255` for a `u8`. Float fields need an explicit range:
+///   ```compile_fail
+///   #[derive(bevy_egui_controls::ControlPanel)]
+///   pub struct Settings {
+///       #[control(slider)]
+///       pub scale: f32,
+///   }
+///   ```
+/// - `#[control(sliders(<range>))]` renders a slider per element of an array side by side,
+///   all over the same range. Add `vertical`, `logarithmic`, `step = 0.1` and
+///   `labels = ["Low", "Mid", "High"]` after the range as needed, e.g.
+///   `sliders(0.0..=1.0, vertical, labels = ["Low", "Mid", "High"])` for an equalizer.
+///   Each slider is labelled underneath, sliders without a label get their index.
+/// - `#[control(list_slider(<range>))]` renders a numbered row per element of an array or
+///   `Vec`, each with a slider taking the same options as `slider`, e.g.
+///   `list_slider(0.0..=1.0, clamp)` for per-channel mix levels. The range is evaluated once
+///   per frame and shared by every element.
+/// - `#[control(slider_duration(<range>))]` renders a slider over a range of seconds for a
+///   `Duration`, e.g. `slider_duration(0.0..=60.0)` for a cooldown. The range is evaluated
+///   like the one of `slider`, and negative bounds are treated as zero.
+/// - `#[control(angle_deg)]` renders a slider over `0.0..=360.0` degrees for an `f32` or `f64`,
+///   e.g. a heading. `angle_deg(wrap)` wraps the value around instead of clamping it, so
+///   dragging past 360° starts over at 0° and `-90.0` is shown, and stored, as `270.0`.
+/// - `#[control(textbox)]` renders a single line `egui::TextEdit` for a `String`.
+///   Takes optional `max_len = 16` and `width = 120.0` arguments, e.g.
+///   `textbox(max_len = 16, width = 120.0)`. With `max_len` edits are truncated to that
+///   many characters and a character count is shown after the text edit.
+/// - `#[control(textbox, id_from = "uuid")]` derives the id of the text edit from the value
+///   of another field, which has to implement `Hash`, instead of its position in the UI. The
+///   text edit keeps its focus and cursor while the panel moves, e.g. in a reordered `Vec`.
+/// - `#[control(addr)]` renders a textbox for an `IpAddr`, `SocketAddr` or any other type
+///   implementing `Display` and `FromStr`. The field is only written back when the text
+///   parses, invalid text is painted in the error color, with the parse error shown on hover,
+///   and does not count as a change.
+/// - `#[control(readonly)]` renders the `Display` formatting of the field in a label, e.g. for
+///   stats that are only shown. Add `display_via = "0"` or `display_via = "name"` to display
+///   a field of the value instead, e.g. the number inside a `struct Meters(f32)` newtype that
+///   does not implement `Display` itself.
+///   Add `fmt = "{:.2}"` to format the value with the given format string instead, e.g.
+///   `fmt = "{:.1} ms"` for a frame time. It has to have exactly one placeholder, which the
+///   value is formatted into.
+///   With `since`, as in `#[control(readonly, since)]`, an `Instant` is displayed as how long
+///   ago it was, e.g. "2.5s ago", and the panel is repainted so it keeps counting.
+///   A bevy `Entity`, which does not implement `Display`, is shown with its `Debug`
+///   formatting, i.e. its index and generation such as `7v0`.
+/// - `#[control(entity_picker = "targets")]` renders a combo box reassigning an `Entity` to one
+///   of the candidates returned by `self.targets()`, a `&[(Entity, String)]` or
+///   `Vec<(Entity, String)>` of entities and the names shown for them. The macro cannot query
+///   the world, so the method has to return candidates gathered elsewhere, e.g. by a system.
+/// - `#[control(file)]` renders a textbox for a `String` or `PathBuf` path followed by a
+///   "Browse…" button. With the `rfd` feature the button picks a file in a native dialog
+///   through [rfd](https://docs.rs/rfd), otherwise it is disabled.
+/// - `#[control(bool)]` renders an `egui::Checkbox` for a `bool`.
+///   `#[control(bool(wide))]` renders a toggle labelled with the doc comment instead, or the
+///   humanized field name, so clicking anywhere on the text toggles it. The doc comment is then
+///   not repeated after it, unless it is in a help popup.
+/// - `#[control(tristate)]` renders three selectable labels for an `Option<bool>`, "Default"
+///   for `None`, "On" for `Some(true)` and "Off" for `Some(false)`, e.g. for settings that
+///   inherit their value unless overridden. The labels are overridden with
+///   `tristate(none = "...", on = "...", off = "...")`.
+/// - `#[control(multiselect)]` renders a checkbox per variant for a `Vec` of a unit-only enum
+///   deriving `ControlPanel`, through its `VARIANTS`, e.g. for the active layers of a map.
+///   Checking a variant appends it, so the `Vec` keeps the order they were checked in, and
+///   unchecking removes it. The enum has to implement `Clone` and `PartialEq`.
+/// - `#[control(button)]` renders a button for a `bool`, labelled like `bool(wide)`. The field
+///   is set to whether the button was clicked this frame, which counts as a change, so
+///   `ui(ui).clicked()` reports clicks on any button of the panel. On structs,
+///   `clicked_action` shows the panel like `ui` and returns the name of the button field
+///   clicked this frame, if any, e.g. `Some("respawn")`, for dispatching on it.
+/// - `#[control(color)]` renders a color picker button for a bevy `Color`.
+///   The color is converted to an sRGBA `egui::Color32` for editing
+///   and is only written back when the picker reports a change.
+/// - `#[control(drag)]` renders an `egui::DragValue` for a number.
+///   `drag(clamp_range = 0..=100)` keeps dragging within the range but lets a value outside
+///   of it be typed in, e.g. to try one out. Adding `clamp_existing`, as in
+///   `drag(clamp_range = 0..=100, clamp_existing)`, clamps typed values too, and the field
+///   itself whenever it is shown, so it never leaves the range.
+/// - `#[control(gradient)]` renders a gradient editor for a `Vec<([f32; 4], f32)>` of
+///   linear RGBA colors and their positions from `0.0` to `1.0`. Drag a stop along the bar
+///   to move it, or click it to edit its color or remove it. The stops are kept sorted
+///   by position.
+/// - `#[control(plot)]` renders a read-only line plot of a `Vec` of numbers, e.g. a frame
+///   time history, against their index. Takes optional `height = 80.0` and
+///   `label = "fps history"` arguments, e.g. `plot(height = 120.0, label = "fps history")`,
+///   the label naming the line in a legend.
+/// - `#[control(nested)]` renders the field's own `ui`, for fields whose type derives
+///   `ControlPanel` too.
+///
+/// - `#[control(with = "path::to::function")]` renders the field with your own
+///   `fn(&mut T, &mut egui::Ui) -> egui::Response`, for types no other control supports.
+///
+/// - `#[control(raw(<widget>))]` passes the given widget expression to `ui.add` verbatim,
+///   e.g. `raw(egui::Slider::new(&mut self.opacity, 0.0..=1.0).smart_aim(false))`.
+///
+/// `raw(.method()...)` appends the given builder calls to the widget of a `slider`, `textbox`,
+/// `bool`, `drag` or `angle_deg` control verbatim, e.g.
+/// `#[control(slider(0.0..=1.0), raw(.text("opacity")))]`. The tokens are spliced in as is,
+/// so errors in them are reported at their own span.
+///
+/// A bare `#[control]` infers the widget from the field type: `drag` for numbers,
+/// `bool` for `bool`, `textbox` for `String`, `color` for `Color`, `addr` for the `std::net`
+/// address types and `nested` for anything else. The macro only sees the name of the type,
+/// so e.g. an enum field is rendered through its own derived selector rather than a combo box.
+///
+/// `Vec<T>` and `Option<T>` are inferred from `T`, recursively, so e.g. a `Vec<Option<f32>>`
+/// renders a numbered row per item, each with a checkbox toggling the optional drag value.
+/// Checking the box of an `Option` fills in `T::default()`. Items can be edited, but not
+/// added or removed.
+///
+/// A `std::num::Wrapping<T>` or `Saturating<T>` field, e.g. a wrapping frame counter, is edited
+/// through the number inside it, so `#[control(slider(0..=100))]` works on a `Wrapping<u32>`
+/// like on a `u32`.
+///
+/// Explicit widget kinds are checked against the field types the macro recognizes by name,
+/// e.g. `#[control(bool)]` on an `f32` field is an error pointing at the field. Other types,
+/// such as aliases, are left to the compiler.
+///
+/// Add `on_change = "method"` to a control, e.g. `#[control(slider(0.0..=1.0), on_change = "recompute")]`,
+/// to call `self.method()` whenever the widget reports a change. The method must take `&mut self`.
+///
+/// Add `debounce = <seconds>` next to `on_change`, e.g.
+/// `#[control(slider(0.0..=1.0), on_change = "rebuild", debounce = 0.2)]`, to hold the call
+/// back for expensive methods. The method is then called once per burst of changes, either
+/// when the drag changing the field is released or once the widget has gone unchanged for the
+/// given number of seconds, whichever comes first. Values are still written to the field
+/// immediately, and a repaint is requested so the call happens without further input.
+///
+/// Add `show_value` to a control, e.g. `#[control(bool, show_value)]`, to render the `Debug`
+/// formatting of the field's current value in monospace after the widget.
+///
+/// Add `unit = "m/s"` to a control, e.g. `#[control(slider(0.0..=500.0), unit = "m/s")]`, to
+/// render the unit as a label after the widget, outside of the value itself. With
+/// `#[controls(unit_column)]` the units line up in a column of their own.
+///
+/// Add `validate = "path::to::function"` to a control, e.g.
+/// `#[control(textbox, validate = "not_empty")]`, to check the value of the field with a
+/// `fn(&T) -> Result<(), String>`. While it returns an error, the error is rendered after the
+/// widget in the error color, and the value is still written to the field. Structs with
+/// validated fields get `validation_errors(&self) -> Vec<(&'static str, String)>`, the name
+/// and error of every field currently failing its check, e.g. for a "Save" button.
+///
+/// Add `help` to a control, e.g. `#[control(slider(0.0..=1.0), help)]`, to replace the doc
+/// comment label with a "?" button opening it in a popup, line breaks included. Only one
+/// popup is open at a time.
+///
+/// Add `advanced` to a control, e.g. `#[control(bool, advanced)]`, to move it into a
+/// single "Advanced" header after all other fields, collapsed by default.
+///
+/// Add `accent = "0xRRGGBB"` (or `"#RRGGBB"`, optionally with an alpha byte) to a control,
+/// e.g. `#[control(slider(0.0..=1.0, fill), accent = "0xFF8800")]`, to make it stand out.
+/// The selection color, e.g. the filled part of a slider, and the fill of hovered and
+/// dragged widgets, e.g. its handle, are set to the accent for that widget only.
+///
+/// Add `lock` to a control on a `Mutex` or `RwLock` field, possibly behind an `Arc`, e.g.
+/// `#[control(lock, nested)]`, to edit the value inside. The lock is taken for writing while
+/// the row is rendered and released right after, so `ui` blocks while another thread holds it.
+/// A poisoned lock renders an error label instead of the widget.
+///
+/// `#[control(skip)]` renders nothing for the field, the same as leaving out the attribute.
+/// It cannot be combined with a widget kind or `on_change`:
+/// ```compile_fail
+/// #[derive(bevy_egui_controls::ControlPanel)]
+/// pub struct Settings {
+///     #[control(skip, slider(0..=1))]
+///     pub level: u8,
+/// }
+/// ```
+///
+/// Neither can `on_change` be combined with `plot`, since plots are read-only:
+/// ```compile_fail
+/// #[derive(bevy_egui_controls::ControlPanel)]
+/// pub struct Dashboard {
+///     #[control(plot, on_change = "refresh")]
+///     pub fps: Vec<f32>,
+/// }
+///
+/// impl Dashboard {
+///     fn refresh(&mut self) {}
+/// }
+/// ```
+///
+/// Zero-sized marker fields, i.e. `PhantomData<...>` and `()`, never render anything,
+/// even with a `#[control]` attribute.
+///
+/// A field may have at most one `#[control]` attribute.
+///
+/// # Enums
+/// Deriving on an enum renders a selectable label per variant, labelled with the
+/// name of the variant as written, e.g. `HighQuality`. The labels are string literals, so
+/// they are not formatted every frame and neither `Display` nor a strum rename such as
+/// `#[strum(serialize = "...")]` changes them. Add `#[control(label = "High quality")]` to a
+/// variant to label it differently. Enums with only unit variants also get `VARIANTS` and
+/// `VARIANT_LABELS` constants listing every variant and its label.
+///
+/// Variants carrying data are constructed with [strum](https://docs.rs/strum)'s
+/// `IntoEnumIterator` when selected, so such enums also need `strum::EnumIter`.
+/// Fields of a variant can have `#[control]` attributes just like struct fields, and
+/// the fields of the selected variant are rendered in a group beneath the selector.
+/// `on_change` is not supported on them, since `self` is borrowed by the variant.
+/// The value of a newtype variant, e.g. `Scale(f32)`, is rendered with its inferred widget
+/// even without an attribute, unless it has `#[control(skip)]`.
+///
+/// `#[control(skip)]` on a variant itself leaves it out of the selector, e.g. for internal
+/// states that should not be picked by hand. Its label is still shown while it is the
+/// current value, so the panel never hides what the value is. `VARIANTS` and
+/// `VARIANT_LABELS` still list it.
+///
+/// An enum without variants has nothing to select, so deriving on it is a compile error:
+/// ```compile_fail
+/// #[derive(bevy_egui_controls::ControlPanel)]
+/// pub enum Never {}
+/// ```
+///
+/// # Features
+/// The generated code only depends on `egui`, the cargo features pick which path it is
+/// resolved through by default:
+/// - `bevy_egui` (default) emits `::bevy_egui::egui` paths.
+/// - `egui` emits `::egui` paths, for sharing settings with plain `egui`/`eframe` apps.
+///   Disable the default features to use it, the two are mutually exclusive.
+/// - `bevy` additionally emits `window_system(title)`, returning a bevy system that shows the
+///   type, as a resource, in an `egui::Window` every frame:
+///   `app.add_systems(Update, Settings::window_system("Settings"))`. Implies `bevy_egui`.
+/// - `rfd` makes the "Browse…" button of `file` controls open a native file dialog.
+///   Your crate needs to depend on `rfd` for it.
+/// - `inspector` additionally emits `inspector_egui_impl()`, so the type shows up in
+///   `bevy-inspector-egui` windows through its generated `ui` instead of the generic
+///   reflection UI. Insert it into the type's registration:
+///   `registry.get_mut(TypeId::of::<Settings>()).unwrap().insert(Settings::inspector_egui_impl())`.
+///
+/// # Panel options
+/// - `#[controls(layout = "top_down" | "left_to_right" | "right_to_left")]` picks the
+///   direction the fields flow in. Defaults to `"top_down"`.
+/// - `#[controls(align = "min" | "center" | "max")]` picks how the fields are aligned
+///   within that layout. Defaults to `"min"`.
+/// - `#[controls(missing_doc = "...")]` renders the given text next to fields that
+///   have no doc comment. By default such fields get no description at all.
+/// - `#[controls(show_description)]` renders the doc comment of the type itself
+///   as a wrapped label above the fields.
+/// - `#[controls(request_focus_first)]` focuses the first editable widget the first time
+///   the panel is shown, so users can start typing right away. The fields are rendered in
+///   declaration order, which is also the order Tab moves focus through them in.
+/// - `#[controls(show_values)]` adds `show_value` to every control.
+/// - `#[controls(collapsible)]` wraps the panel in an `egui::CollapsingHeader` titled with
+///   the name of the type, or the given title with `collapsible = "Graphics"`, collapsed at
+///   first. Nested panels can each have their own, e.g. to fold the sections of a settings
+///   resource.
+/// - `#[controls(label_gap = 12.0)]` adds the given space between every widget and its
+///   description, on top of the usual item spacing.
+/// - `#[controls(docs = "inline" | "short" | "popup" | "icon" | "hidden")]` picks how field doc
+///   comments are rendered, `"short"` only renders their first line, showing the rest when it
+///   is hovered, `"popup"` adds `help` to every control, `"icon"` renders a small "ⓘ" after
+///   every widget showing the doc comment when hovered, and `"hidden"` leaves them out, along
+///   with `missing_doc`, so only the widgets and names remain. Controls with `help` still get
+///   their popup. Defaults to `"inline"`.
+/// - `#[controls(no_docs)]` is shorthand for `docs = "hidden"`, and cannot be combined with
+///   another `docs` mode.
+/// - `#[controls(docs_as_help_icon)]` is shorthand for `docs = "icon"`, and likewise cannot
+///   be combined with another `docs` mode.
+/// - `#[controls(touch)]` enlarges the widgets, e.g. checkboxes and slider handles, so they
+///   are easier to hit on touch screens. Only the panel is affected, including the panels
+///   of nested fields.
+/// - `#[controls(grid)]` lays the fields out in an `egui::Grid`, with the name of every
+///   field in the first column and its widget, followed by its description, in the second.
+///   Names are humanized, e.g. `max_speed` is named "Max speed". Grids size their columns
+///   on the first frame they are shown and only paint from the second one.
+/// - `#[controls(align_widgets = "left" | "right")]` aligns the widgets within the second
+///   column of the grid, `"right"` lines them up along the right edge of the panel.
+///   Defaults to `"left"`, and requires `grid`.
+/// - `#[controls(unit_column)]` lays the fields out in a grid like `grid`, with a third column
+///   holding the `unit` of every field, left empty for fields without one, and their
+///   descriptions in a fourth, e.g. for dense tables of parameters.
+/// - `#[controls(labels_from_serde)]` labels every field before its widget with the name it
+///   is serialized under, i.e. its `#[serde(rename = "...")]`, or its humanized name, e.g.
+///   `max_speed` is labelled "Max speed".
+/// - `#[controls(method = "render_settings")]` names the generated method rendering the panel
+///   `render_settings` instead of `ui`, e.g. when the type already has a `ui` method. The
+///   other generated methods call it. `nested` fields call `ui`, so a renamed panel is nested
+///   with `with = "Settings::render_settings"` instead.
+/// - `#[controls(summary)]` generates `summary(&self) -> String` listing the humanized name
+///   and `Display` formatting of every field on one line, e.g. `"Move speed: 5.5, Vsync: true"`,
+///   for tooltips, logs or collapsed headers. `summary(debug)` uses `Debug` instead. Buttons
+///   and `lock` fields are left out. Only supported on structs.
+/// - `#[controls(track_dirty)]` generates `take_dirty(&self, snapshot: &mut Option<Self>)`,
+///   returning whether the value changed since `snapshot` was taken, through the panel or
+///   otherwise, and taking a new one, e.g. to save settings only when needed. It requires
+///   `Clone` and `PartialEq`. The snapshot is kept by the caller, e.g. in a field next to the
+///   value or a `Local` of a bevy system, so the first call with `None` only takes the
+///   snapshot and returns `false`.
+/// - `#[controls(control_rects)]` records the rect of every widget in egui's temp data as it
+///   is shown, and generates `control_rect(ctx: &egui::Context, field: &str)` returning the
+///   rect the widget of `field` was last shown at, e.g. to scroll to or highlight a setting
+///   from elsewhere. Fields of enum variants are named after their variant, e.g.
+///   `"Fly.altitude"` or `"Walk.0"`. The rect is only keyed by the type and the field, so
+///   when a panel is shown several times in a frame, the last one wins. This is a lookup of
+///   where the widget is, not the widget's own `egui::Id`.
+/// - `#[controls(reset_field)]` generates `reset_field(&mut self, field: &str) -> bool`,
+///   setting the field named `field`, as in `control_rect`, back to its value in
+///   `Self::default()` and returning whether the panel has such a field, e.g. for a "Reset"
+///   entry in a context menu. It requires `Default`. Only supported on structs.
+/// - `#[controls(egui_crate = path::to::egui)]` resolves every emitted `egui` item through
+///   the given path instead of the default root, e.g. when `egui` is re-exported by
+///   another crate.
+/// - `#[controls(strum_crate = path::to::strum)]` resolves `IntoEnumIterator` through the
+///   given path instead of `::strum`.
+///   `#[controls(strum_path = "path::to::strum")]` does the same, taking the path as a string.
+#[proc_macro_derive(ControlPanel, attributes(control, controls))]
 pub fn derive(input: TokenStream) -> TokenStream {
 	parse::expand(parse_macro_input!(input as DeriveInput))
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
 }
@@ -1,138 +1,3625 @@
-use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{parenthesized, token};
 use syn::{
-	Data, DataEnum, DataStruct, DeriveInput, Expr, ExprLit, Fields, Lit, Meta, MetaList,
-	MetaNameValue,
+	parse_quote, Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, ExprLit, Field, Fields,
+	Index, Lit, LitStr, Member, Meta, MetaNameValue, Path, Token, Type, TypePath, Variant,
 };
 
-/// Parse struct fields into an iterator over the
-/// doc comments of fields in the order of definition.
-fn parse_doc_comments_from_fields(fields: &Fields) -> impl Iterator<Item = String> + '_ {
-	fields.iter().map(|field| {
-		let mut doc_comments = vec![];
-
-		// Every individual doc comment is an attr.
-		field.attrs.iter().for_each(|attr| {
-			if let Meta::NameValue(MetaNameValue { path, value, .. }) = &attr.meta {
-				path.segments.iter().for_each(|segment| {
-					if segment.ident == "doc" {
-						if let Expr::Lit(ExprLit {
-							lit: Lit::Str(lit_str),
-							..
-						}) = value
-						{
-							let mut raw_token = lit_str.token().to_string();
-							if let Some(stripped) = raw_token.strip_prefix('\"') {
-								raw_token = stripped.to_string();
+/// Options parsed from the container-level `#[controls(...)]` attributes.
+#[derive(Clone)]
+struct ContainerArgs {
+	/// The `egui::Layout` constructor used for the generated body.
+	layout: Ident,
+	/// The `egui::Align` variant passed to the layout constructor.
+	align: Ident,
+	/// Whether to render the doc comment of the type itself above the fields.
+	show_description: bool,
+	/// The label rendered for fields without a doc comment, if any.
+	missing_doc: Option<String>,
+	/// The path every emitted `egui` item is resolved through.
+	egui_crate: Path,
+	/// The path `IntoEnumIterator` is resolved through for enums.
+	strum_crate: Path,
+	/// Whether to focus the first editable widget the first time the panel is shown.
+	request_focus_first: bool,
+	/// Whether to render the value of every field after its widget.
+	show_values: bool,
+	/// Whether to move the doc comment of every field into a help popup.
+	help: bool,
+	/// Whether to move the doc comment of every field into the hover text of a "ⓘ" icon.
+	help_icon: bool,
+	/// Whether to leave out the doc comments of the fields, and `missing_doc`.
+	hide_docs: bool,
+	/// Whether to only render the first line of the doc comment of every field, the rest
+	/// being the hover text of that line.
+	short_docs: bool,
+	/// Whether to enlarge the widgets for touch screens.
+	touch: bool,
+	/// Whether to label every field with its `#[serde(rename = "...")]`, or its humanized name.
+	labels_from_serde: bool,
+	/// Whether to lay the fields out in a grid, their names in the first column.
+	grid: bool,
+	/// Whether to right-align the widgets in the second column of the grid.
+	widgets_right: bool,
+	/// Whether the grid gets a third column for the `unit` of every field, moving the
+	/// descriptions into a fourth one.
+	unit_column: bool,
+	/// The name of the generated method rendering the panel.
+	method: Ident,
+	/// The format of every value in the generated `summary`, i.e. `{}` or `{:?}`, along with
+	/// where it was enabled, if it was.
+	summary: Option<(&'static str, Span)>,
+	/// Where `track_dirty` was given, if it was, generating `take_dirty`.
+	track_dirty: Option<Span>,
+	/// Where `reset_field` was given, if it was, generating `reset_field`.
+	reset_field: Option<Span>,
+	/// Whether to record the rect of every widget, generating `control_rect`.
+	control_rects: bool,
+	/// The space added between every widget and its description, if any.
+	label_gap: Option<Expr>,
+	/// Whether to wrap the panel in a collapsing header, along with its title if given.
+	collapsible: Option<Option<LitStr>>,
+}
+
+impl Default for ContainerArgs {
+	fn default() -> Self {
+		Self {
+			layout: Ident::new("top_down", Span::call_site()),
+			align: Ident::new("Min", Span::call_site()),
+			show_description: false,
+			missing_doc: None,
+			egui_crate: if cfg!(feature = "egui") {
+				parse_quote!(::egui)
+			} else {
+				parse_quote!(::bevy_egui::egui)
+			},
+			strum_crate: parse_quote!(::strum),
+			request_focus_first: false,
+			show_values: false,
+			help: false,
+			help_icon: false,
+			hide_docs: false,
+			short_docs: false,
+			touch: false,
+			labels_from_serde: false,
+			grid: false,
+			widgets_right: false,
+			unit_column: false,
+			method: Ident::new("ui", Span::call_site()),
+			summary: None,
+			track_dirty: None,
+			reset_field: None,
+			control_rects: false,
+			label_gap: None,
+			collapsible: None,
+		}
+	}
+}
+
+impl ContainerArgs {
+	/// Parse every `#[controls(...)]` attribute on the derive input.
+	fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+		let mut args = Self::default();
+		let mut align_widgets = None;
+		let mut docs = None;
+		let mut no_docs = None;
+		let mut docs_as_help_icon = None;
+		for attr in attrs.iter().filter(|attr| attr.path().is_ident("controls")) {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("layout") {
+					let value: LitStr = meta.value()?.parse()?;
+					let layout = match value.value().as_str() {
+						"top_down" => "top_down",
+						"left_to_right" => "left_to_right",
+						"right_to_left" => "right_to_left",
+						_ => return Err(syn::Error::new_spanned(
+							value,
+							"expected one of \"top_down\", \"left_to_right\" or \"right_to_left\"",
+						)),
+					};
+					args.layout = Ident::new(layout, value.span());
+					Ok(())
+				} else if meta.path.is_ident("align") {
+					let value: LitStr = meta.value()?.parse()?;
+					let align = match value.value().as_str() {
+						"min" => "Min",
+						"center" => "Center",
+						"max" => "Max",
+						_ => {
+							return Err(syn::Error::new_spanned(
+								value,
+								"expected one of \"min\", \"center\" or \"max\"",
+							))
+						}
+					};
+					args.align = Ident::new(align, value.span());
+					Ok(())
+				} else if meta.path.is_ident("show_description") {
+					args.show_description = true;
+					Ok(())
+				} else if meta.path.is_ident("missing_doc") {
+					let value: LitStr = meta.value()?.parse()?;
+					args.missing_doc = Some(value.value());
+					Ok(())
+				} else if meta.path.is_ident("egui_crate") {
+					args.egui_crate = meta.value()?.parse()?;
+					Ok(())
+				} else if meta.path.is_ident("strum_crate") {
+					args.strum_crate = meta.value()?.parse()?;
+					Ok(())
+				} else if meta.path.is_ident("strum_path") {
+					let value: LitStr = meta.value()?.parse()?;
+					args.strum_crate = value.parse().map_err(|_| {
+						syn::Error::new_spanned(
+							&value,
+							"strum_path expects the path strum is re-exported under, e.g. \"my_crate::strum\"",
+						)
+					})?;
+					Ok(())
+				} else if meta.path.is_ident("request_focus_first") {
+					args.request_focus_first = true;
+					Ok(())
+				} else if meta.path.is_ident("show_values") {
+					args.show_values = true;
+					Ok(())
+				} else if meta.path.is_ident("touch") {
+					args.touch = true;
+					Ok(())
+				} else if meta.path.is_ident("labels_from_serde") {
+					args.labels_from_serde = true;
+					Ok(())
+				} else if meta.path.is_ident("method") {
+					let value: LitStr = meta.value()?.parse()?;
+					args.method = value.parse().map_err(|_| {
+						syn::Error::new_spanned(&value, "method expects the name of a method")
+					})?;
+					Ok(())
+				} else if meta.path.is_ident("summary") {
+					let span = meta.path.span();
+					args.summary = Some(("{}", span));
+					if meta.input.peek(token::Paren) {
+						meta.parse_nested_meta(|meta| {
+							if meta.path.is_ident("debug") {
+								args.summary = Some(("{:?}", span));
+								Ok(())
+							} else {
+								Err(meta.error("unsupported summary option"))
 							}
-							if let Some(stripped) = raw_token.strip_suffix('\"') {
-								raw_token = stripped.to_string();
+						})?;
+					}
+					Ok(())
+				} else if meta.path.is_ident("collapsible") {
+					let title = match meta.input.peek(Token![=]) {
+						true => Some(meta.value()?.parse()?),
+						false => None,
+					};
+					args.collapsible = Some(title);
+					Ok(())
+				} else if meta.path.is_ident("label_gap") {
+					args.label_gap = Some(meta.value()?.parse()?);
+					Ok(())
+				} else if meta.path.is_ident("track_dirty") {
+					args.track_dirty = Some(meta.path.span());
+					Ok(())
+				} else if meta.path.is_ident("reset_field") {
+					args.reset_field = Some(meta.path.span());
+					Ok(())
+				} else if meta.path.is_ident("control_rects") {
+					args.control_rects = true;
+					Ok(())
+				} else if meta.path.is_ident("grid") {
+					args.grid = true;
+					Ok(())
+				} else if meta.path.is_ident("unit_column") {
+					args.grid = true;
+					args.unit_column = true;
+					Ok(())
+				} else if meta.path.is_ident("align_widgets") {
+					let value: LitStr = meta.value()?.parse()?;
+					args.widgets_right = match value.value().as_str() {
+						"left" => false,
+						"right" => true,
+						_ => {
+							return Err(syn::Error::new_spanned(
+								value,
+								"expected one of \"left\" or \"right\"",
+							))
+						}
+					};
+					align_widgets = Some(value);
+					Ok(())
+				} else if meta.path.is_ident("docs") {
+					let value: LitStr = meta.value()?.parse()?;
+					let mode = match value.value().as_str() {
+						"inline" => (false, false, false, false),
+						"short" => (false, false, false, true),
+						"popup" => (true, false, false, false),
+						"icon" => (false, true, false, false),
+						"hidden" => (false, false, true, false),
+						_ => {
+							return Err(syn::Error::new_spanned(
+								value,
+								"expected one of \"inline\", \"short\", \"popup\", \"icon\" or \"hidden\"",
+							))
+						}
+					};
+					(args.help, args.help_icon, args.hide_docs, args.short_docs) = mode;
+					docs = Some(value);
+					Ok(())
+				} else if meta.path.is_ident("no_docs") {
+					args.hide_docs = true;
+					no_docs = Some(meta.path.span());
+					Ok(())
+				} else if meta.path.is_ident("docs_as_help_icon") {
+					args.help_icon = true;
+					docs_as_help_icon = Some(meta.path.span());
+					Ok(())
+				} else {
+					Err(meta.error("unsupported controls option"))
+				}
+			})?;
+		}
+		// `no_docs` is shorthand for `docs = "hidden"`, so only the two agreeing is fine.
+		if let (Some(no_docs), Some(docs)) = (no_docs, &docs) {
+			if docs.value() != "hidden" {
+				return Err(syn::Error::new(
+					no_docs,
+					format!(
+						"`no_docs` hides the docs, which contradicts `docs = {:?}`",
+						docs.value()
+					),
+				));
+			}
+		}
+		// Likewise `docs_as_help_icon` is shorthand for `docs = "icon"`.
+		if let Some(docs_as_help_icon) = docs_as_help_icon {
+			let contradicts = match (&docs, no_docs) {
+				(Some(docs), _) if docs.value() != "icon" => {
+					Some(format!("docs = {:?}", docs.value()))
+				}
+				(_, Some(_)) => Some("no_docs".to_string()),
+				_ => None,
+			};
+			if let Some(contradicts) = contradicts {
+				return Err(syn::Error::new(
+					docs_as_help_icon,
+					format!("`docs_as_help_icon` contradicts `{contradicts}`"),
+				));
+			}
+		}
+		if let (Some(align_widgets), false) = (align_widgets, args.grid) {
+			return Err(syn::Error::new_spanned(
+				align_widgets,
+				"`align_widgets` aligns the widgets within their grid column, add `grid`",
+			));
+		}
+		Ok(args)
+	}
+
+	/// The wrapped label describing the whole panel, if enabled and documented.
+	fn description_tokens(&self, attrs: &[Attribute]) -> TokenStream2 {
+		if !self.show_description {
+			return quote!();
+		}
+		let egui = &self.egui_crate;
+		match parse_doc_comment(attrs) {
+			Some(description) => quote! {
+				ui.add(#egui::Label::new(#description).wrap(true));
+			},
+			None => quote!(),
+		}
+	}
+
+	/// The statement binding `request_focus` to whether this is the first time the panel is
+	/// shown, remembered in egui's memory under the id of the `Ui` it is shown in.
+	fn request_focus_tokens(&self) -> TokenStream2 {
+		if !self.request_focus_first {
+			return quote!();
+		}
+		quote! {
+			let request_focus = {
+				let id = ui.id().with("request_focus_first");
+				ui.data_mut(|data| data.get_temp::<()>(id).is_none() && {
+					data.insert_temp(id, ());
+					true
+				})
+			};
+		}
+	}
+
+	/// With `track_dirty`, the `take_dirty` method comparing the value against a snapshot
+	/// taken by the previous call.
+	///
+	/// A derive cannot add a field to hold the snapshot, so the caller owns it, e.g. next to
+	/// the value, and it goes away with them. The bounds are spanned at `track_dirty`, so a
+	/// type missing them is reported there.
+	fn track_dirty_tokens(&self) -> TokenStream2 {
+		let Some(span) = self.track_dirty else {
+			return TokenStream2::new();
+		};
+		let bounds = quote_spanned!(span=> Self: ::std::clone::Clone + ::std::cmp::PartialEq);
+		quote! {
+			/// Whether the value changed since `snapshot` was taken, by the widgets or
+			/// otherwise, replacing it with a new snapshot to compare the next call against.
+			///
+			/// Without a snapshot yet, i.e. `None`, this only takes one and returns `false`.
+			pub fn take_dirty(&self, snapshot: &mut ::std::option::Option<Self>) -> bool
+			where
+				#bounds,
+			{
+				let dirty = snapshot.as_ref().is_some_and(|snapshot| *snapshot != *self);
+				*snapshot = ::std::option::Option::Some(::std::clone::Clone::clone(self));
+				dirty
+			}
+		}
+	}
+
+	/// The body of the generated `method` of the type named `name`, wrapped in a collapsing
+	/// header titled with the name, or the given title, with `collapsible`.
+	///
+	/// The header's response is merged with the panel's, so clicking it counts as a click on
+	/// the panel but not as a change.
+	fn collapsible_tokens(&self, name: &Ident, body: TokenStream2) -> TokenStream2 {
+		let Some(title) = &self.collapsible else {
+			return body;
+		};
+		let egui = &self.egui_crate;
+		let title = match title {
+			Some(title) => title.value(),
+			None => name.to_string(),
+		};
+		quote! {
+			let header = #egui::CollapsingHeader::new(#title).show(ui, |ui| { #body });
+			match header.body_returned {
+				Some(body) => header.header_response | body,
+				None => header.header_response,
+			}
+		}
+	}
+
+	/// The statements enlarging the widgets of the body for touch screens, if enabled.
+	///
+	/// They run on the `Ui` of the body, so they only last for the panel, and nested panels
+	/// inherit them.
+	fn touch_tokens(&self) -> TokenStream2 {
+		if !self.touch {
+			return quote!();
+		}
+		quote! {
+			let spacing = ui.spacing_mut();
+			spacing.interact_size *= 1.75;
+			spacing.slider_width *= 1.75;
+			spacing.icon_width *= 1.75;
+			spacing.icon_width_inner *= 1.75;
+		}
+	}
+
+	/// The `egui::Layout` the generated body is laid out with.
+	fn layout_tokens(&self) -> TokenStream2 {
+		let Self {
+			layout,
+			align,
+			egui_crate: egui,
+			..
+		} = self;
+		quote!(#egui::Layout::#layout(#egui::Align::#align))
+	}
+}
+
+/// Parse the doc comment of an item into a single line,
+/// joining every line of the doc comment with a space.
+fn parse_doc_comment(attrs: &[Attribute]) -> Option<String> {
+	let doc_comments = parse_doc_lines(attrs);
+	if doc_comments.is_empty() {
+		return None;
+	}
+	Some(doc_comments.join(" "))
+}
+
+/// Parse the doc comment of an item, keeping its line breaks.
+fn parse_doc_text(attrs: &[Attribute]) -> Option<String> {
+	let doc_comments = parse_doc_lines(attrs);
+	if doc_comments.is_empty() {
+		return None;
+	}
+	Some(doc_comments.join("\n"))
+}
+
+/// Parse the doc comment of an item into its first line and the rest of it, keeping its line
+/// breaks, if there is more to it.
+fn parse_doc_short(attrs: &[Attribute]) -> Option<(String, Option<String>)> {
+	let mut lines = parse_doc_lines(attrs).into_iter();
+	let short = lines.next()?;
+	let rest: Vec<_> = lines.skip_while(|line| line.is_empty()).collect();
+	Some((short, (!rest.is_empty()).then(|| rest.join("\n"))))
+}
+
+/// Every line of the doc comment of an item, trimmed.
+fn parse_doc_lines(attrs: &[Attribute]) -> Vec<String> {
+	let mut doc_comments = vec![];
+
+	// Every individual doc comment is an attr.
+	attrs.iter().for_each(|attr| {
+		if let Meta::NameValue(MetaNameValue { path, value, .. }) = &attr.meta {
+			path.segments.iter().for_each(|segment| {
+				if segment.ident == "doc" {
+					if let Expr::Lit(ExprLit {
+						lit: Lit::Str(lit_str),
+						..
+					}) = value
+					{
+						// Collect every line of doc-comment, `value` takes care
+						// of unescaping quotes and backslashes for us. A block doc
+						// comment is a single attribute spanning several lines,
+						// possibly starting with ` * ` and padded with blank lines.
+						let value = lit_str.value();
+						let lines: Vec<_> = value
+							.lines()
+							.map(|line| {
+								let line = line.trim();
+								match line.strip_prefix('*') {
+									Some(rest) if value.contains('\n') => rest.trim(),
+									_ => line,
+								}
+							})
+							.collect();
+						let start = lines.iter().position(|line| !line.is_empty());
+						let end = lines.iter().rposition(|line| !line.is_empty());
+						match (start, end) {
+							(Some(start), Some(end)) => doc_comments
+								.extend(lines[start..=end].iter().map(|line| line.to_string())),
+							_ => doc_comments.push(String::new()),
+						}
+					}
+				}
+			});
+		}
+	});
+	doc_comments
+}
+
+/// The name a field is serialized under according to its `#[serde(rename = "...")]`, or
+/// `#[serde(rename(serialize = "..."))]`, if any.
+///
+/// Every other serde option is left to serde, whose derive owns the attribute.
+fn parse_serde_rename(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+	let mut rename = None;
+	for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("rename") {
+				if meta.input.peek(Token![=]) {
+					rename = Some(meta.value()?.parse::<LitStr>()?.value());
+				} else {
+					meta.parse_nested_meta(|meta| {
+						let value: LitStr = meta.value()?.parse()?;
+						if meta.path.is_ident("serialize") {
+							rename = Some(value.value());
+						}
+						Ok(())
+					})?;
+				}
+			} else if meta.input.peek(Token![=]) {
+				meta.value()?.parse::<Expr>()?;
+			} else if meta.input.peek(token::Paren) {
+				let _content;
+				parenthesized!(_content in meta.input);
+			}
+			Ok(())
+		})?;
+	}
+	Ok(rename)
+}
+
+/// The name of a field for humans, i.e. its identifier with the underscores replaced by
+/// spaces and the first letter capitalized, e.g. `max_speed` is labelled `Max speed`.
+fn humanize(member: &Member) -> String {
+	let name = match member {
+		Member::Named(ident) => ident.unraw().to_string(),
+		Member::Unnamed(index) => index.index.to_string(),
+	};
+	let name = name.trim_matches('_').replace('_', " ");
+	let mut chars = name.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().chain(chars).collect(),
+		None => name,
+	}
+}
+
+/// Whether the field type looks like bevy's `Color`, i.e. its last path segment is `Color`.
+fn is_bevy_color(ty: &Type) -> bool {
+	match ty {
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "Color"),
+		_ => false,
+	}
+}
+
+/// Whether the field type looks like bevy's `Entity`, i.e. its last path segment is `Entity`.
+fn is_entity(ty: &Type) -> bool {
+	match ty {
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "Entity"),
+		_ => false,
+	}
+}
+
+/// The values a field of a fixed size integer type can hold, by its last path segment.
+///
+/// `isize` and `usize` depend on the target, so they are left to the compiler.
+fn int_bounds(ty: &Type) -> Option<(i128, i128)> {
+	let Type::Path(TypePath { path, .. }) = ty else {
+		return None;
+	};
+	Some(match path.segments.last()?.ident.to_string().as_str() {
+		"i8" => (i8::MIN.into(), i8::MAX.into()),
+		"i16" => (i16::MIN.into(), i16::MAX.into()),
+		"i32" => (i32::MIN.into(), i32::MAX.into()),
+		"i64" => (i64::MIN.into(), i64::MAX.into()),
+		"u8" => (0, u8::MAX.into()),
+		"u16" => (0, u16::MAX.into()),
+		"u32" => (0, u32::MAX.into()),
+		"u64" => (0, u64::MAX.into()),
+		_ => return None,
+	})
+}
+
+/// Check that the integer literal bounds of a range fit in the integer type of the field,
+/// otherwise e.g. `0..=300` on an `i8` only fails as an overflowing literal deep inside the
+/// generated code, or silently wraps.
+fn check_int_range(range: &Expr, ty: &Type) -> syn::Result<()> {
+	let (Expr::Range(range), Some((min, max))) = (range, int_bounds(ty)) else {
+		return Ok(());
+	};
+	for bound in [&range.start, &range.end].into_iter().flatten() {
+		let value = match &**bound {
+			Expr::Lit(ExprLit {
+				lit: Lit::Int(int), ..
+			}) => int.base10_parse::<i128>()?,
+			Expr::Unary(syn::ExprUnary {
+				op: syn::UnOp::Neg(_),
+				expr,
+				..
+			}) => match &**expr {
+				Expr::Lit(ExprLit {
+					lit: Lit::Int(int), ..
+				}) => -int.base10_parse::<i128>()?,
+				_ => continue,
+			},
+			_ => continue,
+		};
+		if value < min || value > max {
+			return Err(syn::Error::new_spanned(
+				bound,
+				format!(
+					"`{value}` does not fit in `{}`, whose values range from {min} to {max}",
+					quote!(#ty),
+				),
+			));
+		}
+	}
+	Ok(())
+}
+
+/// Whether the field type looks like `Duration`, i.e. its last path segment is `Duration`.
+fn is_duration(ty: &Type) -> bool {
+	match ty {
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "Duration"),
+		_ => false,
+	}
+}
+
+/// Whether the field type is `f32` or `f64`, by its last path segment.
+fn is_float(ty: &Type) -> bool {
+	match ty {
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "f32" || segment.ident == "f64"),
+		_ => false,
+	}
+}
+
+/// Whether the field type is a zero-sized marker with nothing to edit,
+/// i.e. `PhantomData<...>` or `()`.
+fn is_marker(ty: &Type) -> bool {
+	match ty {
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "PhantomData"),
+		Type::Tuple(tuple) => tuple.elems.is_empty(),
+		_ => false,
+	}
+}
+
+/// The type a `Mutex` or `RwLock` field locks, along with the method locking it for writing,
+/// looking through `Arc`, `Rc` and `Box`.
+fn locked_type(ty: &Type) -> Option<(&Type, Ident)> {
+	let Type::Path(TypePath { path, .. }) = ty else {
+		return None;
+	};
+	let segment = path.segments.last()?;
+	let inner = type_argument(segment)?;
+	match segment.ident.to_string().as_str() {
+		"Arc" | "Rc" | "Box" => locked_type(inner),
+		"Mutex" => Some((inner, Ident::new("lock", Span::call_site()))),
+		"RwLock" => Some((inner, Ident::new("write", Span::call_site()))),
+		_ => None,
+	}
+}
+
+/// The names of the number types, by their last path segment.
+const NUMBER_TYPES: &[&str] = &[
+	"f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+	"usize",
+];
+
+/// The names of the other types the macro recognizes by their last path segment, so a widget
+/// expecting another type surely cannot edit them. Any other type may be an alias.
+const KNOWN_TYPES: &[&str] = &[
+	"bool",
+	"char",
+	"String",
+	"PathBuf",
+	"Color",
+	"Duration",
+	"Vec",
+	"Option",
+	"IpAddr",
+	"Ipv4Addr",
+	"Ipv6Addr",
+	"SocketAddr",
+	"SocketAddrV4",
+	"SocketAddrV6",
+	"Entity",
+];
+
+/// Reject a widget kind given for a field whose type it certainly cannot edit, pointing at the
+/// field rather than at the generated code failing to compile.
+///
+/// Only the widgets expecting specific types are checked, and only against types known to be
+/// something else, i.e. listed in `NUMBER_TYPES` or `KNOWN_TYPES`, arrays and tuples.
+fn check_field_type(kind: &WidgetKind, field: &Field, ty: &Type) -> syn::Result<()> {
+	let numbers = NUMBER_TYPES;
+	let (control, expected, accepted): (_, _, &[&str]) = match kind {
+		WidgetKind::Bool | WidgetKind::Toggle(_) => ("bool", "a `bool`", &["bool"]),
+		WidgetKind::Button(_) => ("button", "a `bool`", &["bool"]),
+		WidgetKind::Tristate(_) => ("tristate", "an `Option<bool>`", &["Option"]),
+		WidgetKind::Drag(_) => ("drag", "a number", numbers),
+		WidgetKind::Slider(_) => ("slider", "a number", numbers),
+		WidgetKind::AngleDeg { .. } => ("angle_deg", "an `f32` or `f64`", &["f32", "f64"]),
+		WidgetKind::EntityPicker(_) => ("entity_picker", "an `Entity`", &["Entity"]),
+		WidgetKind::Multiselect => ("multiselect", "a `Vec`", &["Vec"]),
+		WidgetKind::Textbox(_) => ("textbox", "a `String`", &["String"]),
+		WidgetKind::File => ("file", "a `String` or `PathBuf`", &["String", "PathBuf"]),
+		_ => return Ok(()),
+	};
+	let known = match ty {
+		Type::Path(TypePath { path, .. }) => path.segments.last().is_some_and(|segment| {
+			let name = segment.ident.to_string();
+			let name = name.as_str();
+			(NUMBER_TYPES.contains(&name) || KNOWN_TYPES.contains(&name))
+				&& !accepted.contains(&name)
+		}),
+		Type::Array(_) | Type::Tuple(_) => true,
+		_ => false,
+	};
+	if !known {
+		return Ok(());
+	}
+	let ident = &field.ident;
+	let colon = &field.colon_token;
+	Err(syn::Error::new_spanned(
+		quote!(#ident #colon #ty),
+		format!(
+			"`{control}` control requires {expected} field, found `{}`",
+			quote!(#ty).to_string().replace(' ', ""),
+		),
+	))
+}
+
+/// The element type of an array or `Vec`.
+fn element_type(ty: &Type) -> Option<&Type> {
+	match ty {
+		Type::Array(array) => Some(ungroup(&array.elem)),
+		Type::Path(TypePath { path, .. }) => path
+			.segments
+			.last()
+			.filter(|segment| segment.ident == "Vec")
+			.and_then(type_argument),
+		_ => None,
+	}
+}
+
+/// The number inside a `Wrapping` or `Saturating` field, e.g. `u32` for `Wrapping<u32>`.
+fn wrapped_number_type(ty: &Type) -> Option<&Type> {
+	let Type::Path(TypePath { path, .. }) = ty else {
+		return None;
+	};
+	path.segments
+		.last()
+		.filter(|segment| segment.ident == "Wrapping" || segment.ident == "Saturating")
+		.and_then(type_argument)
+}
+
+/// The first type argument of a path segment, e.g. `T` for `Vec<T>`.
+fn type_argument(segment: &syn::PathSegment) -> Option<&Type> {
+	let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+		return None;
+	};
+	arguments.args.iter().find_map(|argument| match argument {
+		syn::GenericArgument::Type(ty) => Some(ungroup(ty)),
+		_ => None,
+	})
+}
+
+/// The type inside any invisible groups and parentheses around it.
+///
+/// A type passed to a `macro_rules!` macro as a `$ty:ty` fragment reaches the derive wrapped
+/// in an invisible group, which would hide it from the checks by last path segment.
+fn ungroup(mut ty: &Type) -> &Type {
+	loop {
+		ty = match ty {
+			Type::Group(group) => &group.elem,
+			Type::Paren(paren) => &paren.elem,
+			_ => return ty,
+		};
+	}
+}
+
+/// The widget a bare `#[control]` renders for a field of the given type.
+///
+/// Only the last path segment is known to the macro, so numbers get an `egui::DragValue`
+/// (a slider would need a range), `bool`, `String` and bevy's `Color` get their usual widgets,
+/// `Vec`s and `Option`s get the widget of their type argument, recursively, and any other
+/// type is assumed to derive `ControlPanel` itself and rendered nested.
+fn infer_widget(ty: &Type) -> WidgetKind {
+	let Type::Path(TypePath { path, .. }) = ty else {
+		return WidgetKind::Nested;
+	};
+	let Some(segment) = path.segments.last() else {
+		return WidgetKind::Nested;
+	};
+	match segment.ident.to_string().as_str() {
+		"f32" | "f64" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32"
+		| "u64" | "u128" | "usize" => WidgetKind::Drag(DragArgs::default()),
+		"bool" => WidgetKind::Bool,
+		"String" => WidgetKind::Textbox(TextboxArgs::default()),
+		"Color" => WidgetKind::Color,
+		"IpAddr" | "Ipv4Addr" | "Ipv6Addr" | "SocketAddr" | "SocketAddrV4" | "SocketAddrV6" => {
+			WidgetKind::Addr
+		}
+		"Vec" | "Option" => match type_argument(segment) {
+			Some(inner) => {
+				let kind = Box::new(infer_widget(inner));
+				let inner = Box::new(inner.clone());
+				match segment.ident == "Vec" {
+					true => WidgetKind::List(kind, inner),
+					false => WidgetKind::Optional(kind, inner),
+				}
+			}
+			None => WidgetKind::Nested,
+		},
+		_ => WidgetKind::Nested,
+	}
+}
+
+/// A field with a `#[control]` attribute, along with what to render for it.
+struct FieldControl {
+	/// How the field is accessed, i.e. its identifier or, for tuple fields, its index.
+	member: Member,
+	/// Statements run before the row, outside of its closure, e.g. evaluating a slider range
+	/// that borrows `self`.
+	setup: TokenStream2,
+	/// The expression rendering the widget into `ui` and evaluating to its `Response`.
+	widget: TokenStream2,
+	/// The description rendered next to the widget, if any.
+	doc: Option<String>,
+	/// The hover text of the description, with `docs = "short"`, if there is more to the doc
+	/// comment than its first line.
+	doc_more: Option<String>,
+	/// The method called on `self` whenever the widget reports a change.
+	on_change: Option<Ident>,
+	/// The seconds the widget has to stay unchanged before `on_change` is called, if any.
+	debounce: Option<Expr>,
+	/// Whether this is the widget focused by `#[controls(request_focus_first)]`.
+	request_focus: bool,
+	/// The place of the field, if its value is rendered after the widget.
+	show_value: Option<TokenStream2>,
+	/// The unit rendered after the widget, in its own column with `unit_column`.
+	unit: Option<LitStr>,
+	/// The place of the field along with the function checking its value, whose error is
+	/// rendered after the widget, if any.
+	validate: Option<(TokenStream2, Path)>,
+	/// The full doc comment shown in a popup behind a "?" button instead of `doc`, if any.
+	help: Option<String>,
+	/// Whether `help` is shown as the hover text of a "ⓘ" icon rather than in a popup.
+	help_icon: bool,
+	/// Whether the row goes into the collapsed "Advanced" header after the other rows.
+	advanced: bool,
+	/// The name rendered before the widget, if any.
+	label: Option<String>,
+	/// The name of the field, e.g. for `control_rect`, and what the id of its rect is derived
+	/// from, see `id_tokens`.
+	name: String,
+	/// Whether the widget is a `button`, reported by `clicked_action`.
+	button: bool,
+	/// Whether the field is a `lock` field, whose value is behind the lock.
+	locked: bool,
+}
+
+impl FieldControl {
+	/// The `egui::Id` the rect of the widget is stored under in egui's temp data with
+	/// `control_rects`, and looked up by `control_rect`. It is derived from the type and
+	/// `name`, so it does not depend on where the panel is shown.
+	fn id_tokens(&self, egui: &Path) -> TokenStream2 {
+		let name = &self.name;
+		quote!(#egui::Id::new((::std::any::type_name::<Self>(), #name)))
+	}
+
+	/// The row rendering the widget followed by its description,
+	/// evaluating to the `InnerResponse` of the row holding the widget's `Response`.
+	///
+	/// In a grid, the label goes into the first column and the rest into the second one,
+	/// which ends the grid row.
+	fn row_tokens(&self, args: &ContainerArgs) -> TokenStream2 {
+		let egui = &args.egui_crate;
+		let member = &self.member;
+		let setup = &self.setup;
+		let widget = &self.widget;
+		let on_change = self.on_change.as_ref().map(|method| match &self.debounce {
+			None => quote! {
+				if response.changed() {
+					self.#method();
+				}
+			},
+			// Remember when the widget last changed, and call the method once the drag is
+			// released or the widget has been left alone for long enough, whichever is first.
+			Some(debounce) => {
+				let salt = format!("debounce {}", quote!(#member));
+				quote! {
+					let pending = ui.make_persistent_id(#salt);
+					let now = ui.input(|input| input.time);
+					if response.changed() {
+						ui.data_mut(|data| data.insert_temp(pending, now));
+					}
+					if let Some(changed) = ui.data(|data| data.get_temp::<f64>(pending)) {
+						let quiet = now - changed;
+						let debounce = (#debounce) as f64;
+						if response.drag_released() || quiet >= debounce {
+							ui.data_mut(|data| data.remove::<f64>(pending));
+							self.#method();
+						} else {
+							ui.ctx().request_repaint_after(
+								::std::time::Duration::from_secs_f64(debounce - quiet),
+							);
+						}
+					}
+				}
+			}
+		});
+		let request_focus = self.request_focus.then(|| {
+			quote! {
+				if request_focus {
+					response.request_focus();
+				}
+			}
+		});
+		let show_value = self
+			.show_value
+			.as_ref()
+			.map(|place| quote!(ui.monospace(format!("{:?}", #place));));
+		// Only add a label when there is something to say, an empty label
+		// would still take up the item spacing after the widget.
+		let doc = match (&self.help, &self.doc) {
+			(Some(help), _) if self.help_icon => Some(quote!(ui.label("ⓘ").on_hover_text(#help);)),
+			(Some(help), _) => {
+				// Salt the popup id with the field, egui only keeps one popup open at a time.
+				let salt = format!("help {}", quote!(#member));
+				Some(quote! {
+					let button = ui.small_button("?");
+					let popup = ui.make_persistent_id(#salt);
+					if button.clicked() {
+						ui.memory_mut(|memory| memory.toggle_popup(popup));
+					}
+					#egui::popup::popup_below_widget(ui, popup, &button, |ui| {
+						ui.set_min_width(200.0);
+						ui.label(#help);
+					});
+				})
+			}
+			(None, Some(doc)) => match &self.doc_more {
+				Some(more) => Some(quote!(ui.label(#doc).on_hover_text(#more);)),
+				None => Some(quote!(ui.label(#doc);)),
+			},
+			(None, None) => None,
+		};
+		let gap = doc
+			.as_ref()
+			.and(args.label_gap.as_ref())
+			.map(|gap| quote!(ui.add_space(#gap);));
+		let label = self.label.as_ref().map(|label| quote!(ui.label(#label);));
+		let record_rect = args.control_rects.then(|| {
+			let id = self.id_tokens(egui);
+			quote!(ui.data_mut(|data| data.insert_temp(#id, response.rect));)
+		});
+		let error = self.validate.as_ref().map(|(place, function)| {
+			quote! {
+				if let ::std::result::Result::Err(error) = #function(&#place) {
+					ui.colored_label(ui.visuals().error_fg_color, error);
+				}
+			}
+		});
+		let widget = quote! {
+			let response = #widget;
+			#record_rect
+			#request_focus
+			#on_change
+		};
+		let body = match args.unit_column {
+			true => quote! {
+				#widget
+				#error
+				#show_value
+				response
+			},
+			false => {
+				let unit = self.unit.as_ref().map(|unit| quote!(ui.label(#unit);));
+				quote! {
+					#widget
+					#unit
+					#error
+					#show_value
+					#gap
+					#doc
+					response
+				}
+			}
+		};
+		if !args.grid {
+			return quote! {
+				{
+					#setup
+					ui.horizontal_wrapped(|ui| {
+						#label
+						#body
+					})
+				}
+			};
+		}
+		let cell = match args.widgets_right {
+			true => quote! {
+				ui.with_layout(#egui::Layout::right_to_left(#egui::Align::Center), |ui| { #body })
+			},
+			false => quote!(ui.horizontal_wrapped(|ui| { #body })),
+		};
+		if args.unit_column {
+			// Every row fills the unit column, so the descriptions line up in the last one.
+			let unit = match &self.unit {
+				Some(unit) => quote!(ui.label(#unit);),
+				None => quote!(ui.label("");),
+			};
+			let doc = doc.map(|doc| quote!(ui.horizontal_wrapped(|ui| { #doc });));
+			return quote! {
+				{
+					#setup
+					#label
+					let row = #cell;
+					#unit
+					#doc
+					ui.end_row();
+					row
+				}
+			};
+		}
+		quote! {
+			{
+				#setup
+				#label
+				let row = #cell;
+				ui.end_row();
+				row
+			}
+		}
+	}
+}
+
+/// The kind of widget a `#[control(...)]` attribute renders.
+enum WidgetKind {
+	Slider(SliderArgs),
+	Textbox(TextboxArgs),
+	Bool,
+	Color,
+	Drag(DragArgs),
+	Nested,
+	Gradient,
+	Plot(PlotArgs),
+	With(Path),
+	Raw(TokenStream2),
+	SliderDuration(Expr),
+	Sliders(SlidersArgs),
+	File,
+	/// The items of a `Vec` of the given type, each rendered with the inner widget.
+	List(Box<WidgetKind>, Box<Type>),
+	/// An `Option` of the given type, toggled by a checkbox and edited with the inner widget.
+	Optional(Box<WidgetKind>, Box<Type>),
+	/// A `bool` toggled by clicking anywhere on its text, filled in once the field is known.
+	Toggle(String),
+	/// A button with the given text, filled in like `Toggle`, setting a `bool` to whether it
+	/// was clicked this frame.
+	Button(String),
+	/// The labels of `None`, `Some(true)` and `Some(false)` of an `Option<bool>`.
+	Tristate([String; 3]),
+	/// A slider per element of an array or `Vec`, all sharing the same options. It becomes a
+	/// `List` of `Slider`s once the element type is known.
+	ListSlider(SliderArgs),
+	/// A slider over `0.0..=360.0` degrees, wrapping the value around into that range
+	/// rather than clamping it if `wrap` is set.
+	AngleDeg {
+		wrap: bool,
+	},
+	/// A textbox editing a value through its `Display` and `FromStr` implementations, e.g. an
+	/// `IpAddr`, only writing back text that parses.
+	Addr,
+	/// A label showing the `Display` formatting of the value, or of the given field of it,
+	/// formatted with `fmt` if given, or how long ago that `Instant` was if `since` is set.
+	Readonly {
+		via: Option<Member>,
+		since: bool,
+		fmt: Option<LitStr>,
+	},
+	/// A combo box picking an `Entity` out of the candidates returned by the given method.
+	EntityPicker(Ident),
+	/// A checkbox per variant of a unit-only enum, toggling whether a `Vec` of the enum
+	/// contains it.
+	Multiselect,
+}
+
+impl WidgetKind {
+	/// Whether the widget is an `egui::Widget` builder passed to `ui.add`,
+	/// which `raw(.method())` calls can be appended to.
+	fn is_builder(&self) -> bool {
+		matches!(
+			self,
+			Self::Slider(_)
+				| Self::Textbox(_)
+				| Self::Bool | Self::Drag(_)
+				| Self::Raw(_)
+				| Self::AngleDeg { .. }
+		)
+	}
+}
+
+/// Options parsed from a field-level `#[control(...)]` attribute.
+#[derive(Default)]
+struct ControlArgs {
+	/// The widget to render for the field, inferred from its type if not given.
+	widget: Option<WidgetKind>,
+	/// The method called on `self` whenever the widget reports a change.
+	on_change: Option<Ident>,
+	/// The seconds the widget has to stay unchanged before `on_change` is called, if any.
+	debounce: Option<Expr>,
+	/// Where `skip` was given, if it was. Skipped fields render nothing.
+	skip: Option<Span>,
+	/// The builder calls of `raw(.method()...)`, appended to the widget verbatim.
+	raw: Option<TokenStream2>,
+	/// Whether to render the value of the field after its widget.
+	show_value: bool,
+	/// Whether the field is a `Mutex` or `RwLock` to lock for editing the value inside.
+	lock: bool,
+	/// Whether to move the doc comment of the field into a help popup.
+	help: bool,
+	/// Whether to move the field into the collapsed "Advanced" header.
+	advanced: bool,
+	/// The sRGBA color the widget is highlighted with, if any.
+	accent: Option<[u8; 4]>,
+	/// The field of the value a `readonly` control displays, as given to `display_via`.
+	display_via: Option<(LitStr, Member)>,
+	/// Where `since` was given, if it was, making a `readonly` control display an `Instant`.
+	since: Option<Span>,
+	/// The format string a `readonly` control formats the value with, as given to `fmt`.
+	fmt: Option<LitStr>,
+	/// The field the id of a `textbox` is derived from, as given to `id_from`.
+	id_from: Option<(LitStr, Ident)>,
+	/// The unit rendered after the widget, e.g. "m/s".
+	unit: Option<LitStr>,
+	/// The function checking the value of the field, as given to `validate`.
+	validate: Option<Path>,
+}
+
+impl ControlArgs {
+	/// Parse a `#[control(...)]` attribute, or a bare `#[control]`.
+	fn from_attr(attr: &Attribute) -> syn::Result<Self> {
+		let mut args = Self::default();
+		if let Meta::Path(_) = attr.meta {
+			return Ok(args);
+		}
+		attr.parse_nested_meta(|meta| {
+			let widget = if meta.path.is_ident("slider") {
+				if !meta.input.peek(token::Paren) {
+					WidgetKind::Slider(SliderArgs {
+						range: None,
+						fill: false,
+						clamp: false,
+						text: None,
+					})
+				} else {
+					let content;
+					parenthesized!(content in meta.input);
+					WidgetKind::Slider(content.parse()?)
+				}
+			} else if meta.path.is_ident("list_slider") {
+				let content;
+				parenthesized!(content in meta.input);
+				WidgetKind::ListSlider(content.parse()?)
+			} else if meta.path.is_ident("angle_deg") {
+				let mut wrap = false;
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						if meta.path.is_ident("wrap") {
+							wrap = true;
+							Ok(())
+						} else {
+							Err(meta.error("unsupported angle_deg option"))
+						}
+					})?;
+				}
+				WidgetKind::AngleDeg { wrap }
+			} else if meta.path.is_ident("entity_picker") {
+				let value: LitStr = meta.value()?.parse()?;
+				let method = value.parse().map_err(|_| {
+					syn::Error::new_spanned(
+						&value,
+						"entity_picker expects the name of a method returning the candidates",
+					)
+				})?;
+				WidgetKind::EntityPicker(method)
+			} else if meta.path.is_ident("readonly") {
+				WidgetKind::Readonly {
+					via: None,
+					since: false,
+					fmt: None,
+				}
+			} else if meta.path.is_ident("id_from") {
+				let value: LitStr = meta.value()?.parse()?;
+				let field = value.parse().map_err(|_| {
+					syn::Error::new_spanned(&value, "id_from expects the name of a field")
+				})?;
+				args.id_from = Some((value, field));
+				return Ok(());
+			} else if meta.path.is_ident("since") {
+				args.since = Some(meta.path.span());
+				return Ok(());
+			} else if meta.path.is_ident("fmt") {
+				let value: LitStr = meta.value()?.parse()?;
+				check_single_placeholder(&value)?;
+				args.fmt = Some(value);
+				return Ok(());
+			} else if meta.path.is_ident("display_via") {
+				let value: LitStr = meta.value()?.parse()?;
+				let member = value.parse().map_err(|_| {
+					syn::Error::new_spanned(
+						&value,
+						"display_via expects a field name or tuple index, e.g. \"0\"",
+					)
+				})?;
+				args.display_via = Some((value, member));
+				return Ok(());
+			} else if meta.path.is_ident("addr") {
+				WidgetKind::Addr
+			} else if meta.path.is_ident("file") {
+				WidgetKind::File
+			} else if meta.path.is_ident("sliders") {
+				if !meta.input.peek(token::Paren) {
+					return Err(meta.error(
+						"sliders expects the range shared by every slider, e.g. `sliders(0.0..=1.0)`",
+					));
+				}
+				let content;
+				parenthesized!(content in meta.input);
+				WidgetKind::Sliders(content.parse()?)
+			} else if meta.path.is_ident("slider_duration") {
+				if !meta.input.peek(token::Paren) {
+					return Err(meta.error(
+						"slider_duration expects a range of seconds, e.g. `slider_duration(0.0..=60.0)`",
+					));
+				}
+				let content;
+				parenthesized!(content in meta.input);
+				WidgetKind::SliderDuration(content.parse()?)
+			} else if meta.path.is_ident("textbox") {
+				let mut textbox = TextboxArgs::default();
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						if meta.path.is_ident("max_len") {
+							textbox.max_len = Some(meta.value()?.parse()?);
+						} else if meta.path.is_ident("width") {
+							textbox.width = Some(meta.value()?.parse()?);
+						} else {
+							return Err(meta.error("unsupported textbox option"));
+						}
+						Ok(())
+					})?;
+				}
+				WidgetKind::Textbox(textbox)
+			} else if meta.path.is_ident("bool") {
+				let mut wide = false;
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						if meta.path.is_ident("wide") {
+							wide = true;
+							Ok(())
+						} else {
+							Err(meta.error("unsupported bool option"))
+						}
+					})?;
+				}
+				match wide {
+					true => WidgetKind::Toggle(String::new()),
+					false => WidgetKind::Bool,
+				}
+			} else if meta.path.is_ident("tristate") {
+				let mut labels = ["Default", "On", "Off"].map(String::from);
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						let index = if meta.path.is_ident("none") {
+							0
+						} else if meta.path.is_ident("on") {
+							1
+						} else if meta.path.is_ident("off") {
+							2
+						} else {
+							return Err(meta.error("unsupported tristate option"));
+						};
+						labels[index] = meta.value()?.parse::<LitStr>()?.value();
+						Ok(())
+					})?;
+				}
+				WidgetKind::Tristate(labels)
+			} else if meta.path.is_ident("button") {
+				WidgetKind::Button(String::new())
+			} else if meta.path.is_ident("multiselect") {
+				WidgetKind::Multiselect
+			} else if meta.path.is_ident("color") {
+				WidgetKind::Color
+			} else if meta.path.is_ident("drag") {
+				let mut drag = DragArgs::default();
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						if meta.path.is_ident("clamp_range") {
+							drag.clamp_range = Some(meta.value()?.parse()?);
+						} else if meta.path.is_ident("clamp_existing") {
+							drag.clamp_existing = Some(meta.path.span());
+						} else {
+							return Err(meta.error("unsupported drag option"));
+						}
+						Ok(())
+					})?;
+				}
+				if let (None, Some(span)) = (&drag.clamp_range, drag.clamp_existing) {
+					return Err(syn::Error::new(
+						span,
+						"clamp_existing needs a range, e.g. `drag(clamp_range = 0..=100, clamp_existing)`",
+					));
+				}
+				WidgetKind::Drag(drag)
+			} else if meta.path.is_ident("nested") {
+				WidgetKind::Nested
+			} else if meta.path.is_ident("gradient") {
+				WidgetKind::Gradient
+			} else if meta.path.is_ident("plot") {
+				let mut plot = PlotArgs::default();
+				if meta.input.peek(token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						if meta.path.is_ident("height") {
+							plot.height = Some(meta.value()?.parse()?);
+						} else if meta.path.is_ident("label") {
+							plot.label = Some(meta.value()?.parse()?);
+						} else {
+							return Err(meta.error("unsupported plot option"));
+						}
+						Ok(())
+					})?;
+				}
+				WidgetKind::Plot(plot)
+			} else if meta.path.is_ident("with") {
+				let value: LitStr = meta.value()?.parse()?;
+				let function = value.parse().map_err(|_| {
+					syn::Error::new_spanned(
+						&value,
+						"with expects the path of a function `fn(&mut T, &mut Ui) -> Response`",
+					)
+				})?;
+				WidgetKind::With(function)
+			} else if meta.path.is_ident("raw") {
+				let content;
+				parenthesized!(content in meta.input);
+				if content.peek(Token![.]) {
+					args.raw = Some(content.parse()?);
+					return Ok(());
+				}
+				WidgetKind::Raw(content.parse()?)
+			} else if meta.path.is_ident("show_value") {
+				args.show_value = true;
+				return Ok(());
+			} else if meta.path.is_ident("unit") {
+				args.unit = Some(meta.value()?.parse()?);
+				return Ok(());
+			} else if meta.path.is_ident("validate") {
+				let value: LitStr = meta.value()?.parse()?;
+				let function = value.parse().map_err(|_| {
+					syn::Error::new_spanned(
+						&value,
+						"validate expects the path of a function `fn(&T) -> Result<(), String>`",
+					)
+				})?;
+				args.validate = Some(function);
+				return Ok(());
+			} else if meta.path.is_ident("advanced") {
+				args.advanced = true;
+				return Ok(());
+			} else if meta.path.is_ident("accent") {
+				args.accent = Some(parse_hex_color(&meta.value()?.parse()?)?);
+				return Ok(());
+			} else if meta.path.is_ident("help") {
+				args.help = true;
+				return Ok(());
+			} else if meta.path.is_ident("lock") {
+				args.lock = true;
+				return Ok(());
+			} else if meta.path.is_ident("skip") {
+				args.skip = Some(meta.path.span());
+				return Ok(());
+			} else if meta.path.is_ident("on_change") {
+				let value: LitStr = meta.value()?.parse()?;
+				let method = value.parse().map_err(|_| {
+					syn::Error::new_spanned(
+						&value,
+						"on_change expects the name of a method taking `&mut self`",
+					)
+				})?;
+				args.on_change = Some(method);
+				return Ok(());
+			} else if meta.path.is_ident("debounce") {
+				args.debounce = Some(meta.value()?.parse()?);
+				return Ok(());
+			} else {
+				return Err(meta.error("unsupported control"));
+			};
+			if args.widget.is_some() {
+				return Err(meta.error("a control renders a single widget"));
+			}
+			args.widget = Some(widget);
+			Ok(())
+		})?;
+		args.validate()?;
+		if let Some(WidgetKind::Readonly { via, since, fmt }) = &mut args.widget {
+			*via = args.display_via.take().map(|(_, member)| member);
+			*since = args.since.is_some();
+			*fmt = args.fmt.take();
+		}
+		Ok(args)
+	}
+
+	/// Reject combinations of options that contradict each other, rather than
+	/// silently ignoring one of them.
+	fn validate(&self) -> syn::Result<()> {
+		if let Some(skip) = self.skip {
+			if self.widget.is_some() {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with a widget kind, the field renders nothing",
+				));
+			}
+			if self.on_change.is_some() {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with `on_change`, the field renders nothing to change",
+				));
+			}
+			if self.show_value {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with `show_value`, the field renders nothing",
+				));
+			}
+			if self.lock {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with `lock`, the field renders nothing",
+				));
+			}
+			if self.unit.is_some() {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with `unit`, the field renders nothing",
+				));
+			}
+			if self.validate.is_some() {
+				return Err(syn::Error::new(
+					skip,
+					"`skip` cannot be combined with `validate`, the field renders nothing",
+				));
+			}
+		}
+		if let (Some(validate), true) = (&self.validate, self.lock) {
+			return Err(syn::Error::new_spanned(
+				validate,
+				"`validate` cannot be combined with `lock`, the value is only reachable through the lock",
+			));
+		}
+		if let (Some(debounce), None) = (&self.debounce, &self.on_change) {
+			return Err(syn::Error::new_spanned(
+				debounce,
+				"`debounce` delays `on_change`, which is missing",
+			));
+		}
+		if let (Some(WidgetKind::Plot(_)), Some(on_change)) = (&self.widget, &self.on_change) {
+			return Err(syn::Error::new_spanned(
+				on_change,
+				"`on_change` cannot be combined with `plot`, plots are read-only",
+			));
+		}
+		if let (Some(WidgetKind::Readonly { .. }), Some(on_change)) =
+			(&self.widget, &self.on_change)
+		{
+			return Err(syn::Error::new_spanned(
+				on_change,
+				"`on_change` cannot be combined with `readonly`, the value is only displayed",
+			));
+		}
+		let readonly = matches!(self.widget, Some(WidgetKind::Readonly { .. }));
+		if let (Some((value, _)), false) = (&self.display_via, readonly) {
+			return Err(syn::Error::new_spanned(
+				value,
+				"`display_via` picks what a `readonly` control displays, add `readonly`",
+			));
+		}
+		if let (Some(since), false) = (self.since, readonly) {
+			return Err(syn::Error::new(
+				since,
+				"`since` displays how long ago an `Instant` was, add `readonly`",
+			));
+		}
+		if let (Some(fmt), false) = (&self.fmt, readonly) {
+			return Err(syn::Error::new_spanned(
+				fmt,
+				"`fmt` formats what a `readonly` control displays, add `readonly`",
+			));
+		}
+		if let (Some(fmt), Some(_)) = (&self.fmt, self.since) {
+			return Err(syn::Error::new_spanned(
+				fmt,
+				"`fmt` cannot be combined with `since`, which formats the elapsed time itself",
+			));
+		}
+		Ok(())
+	}
+}
+
+/// Check that a `fmt` string has exactly one positional placeholder, e.g. `"{:.2}"` or
+/// `"{} m"`, which the value of the field is formatted into. Named placeholders would be
+/// captured from the generated code instead, so they are rejected too, as are widths and
+/// precisions taken from another argument, e.g. `"{:>1$}"` or `"{:.*}"`.
+fn check_single_placeholder(value: &LitStr) -> syn::Result<()> {
+	let text = value.value();
+	let mut chars = text.chars().peekable();
+	let mut placeholders = 0;
+	while let Some(c) = chars.next() {
+		match c {
+			'{' if chars.peek() == Some(&'{') => {
+				chars.next();
+			}
+			'{' => {
+				let spec: String = chars.by_ref().take_while(|&c| c != '}').collect();
+				let (argument, format) = spec.split_once(':').unwrap_or((&spec, ""));
+				if !matches!(argument, "" | "0") {
+					return Err(syn::Error::new_spanned(
+						value,
+						format!(
+							"`fmt` only formats the field, `{{{argument}}}` names something else"
+						),
+					));
+				}
+				// The fill can be any character, e.g. `$` in `{:$>8}`, so skip it before
+				// looking for `$` and `*`, which take a width or precision from an argument.
+				let mut rest = format.chars();
+				let after_fill = match (rest.next(), rest.next()) {
+					(Some(_), Some('<' | '^' | '>')) => rest.as_str(),
+					_ => format,
+				};
+				if after_fill.contains(['$', '*']) {
+					return Err(syn::Error::new_spanned(
+						value,
+						format!(
+							"`fmt` only formats the field, `{{{spec}}}` takes its width or precision from another argument"
+						),
+					));
+				}
+				placeholders += 1;
+			}
+			_ => {}
+		}
+	}
+	if placeholders != 1 {
+		return Err(syn::Error::new_spanned(
+			value,
+			format!(
+				"`fmt` expects exactly one placeholder for the field, e.g. \"{{:.2}}\", found {placeholders}"
+			),
+		));
+	}
+	Ok(())
+}
+
+/// Parse a `"0xRRGGBB"` or `"#RRGGBB"` color, optionally followed by an alpha byte,
+/// into its sRGBA bytes.
+fn parse_hex_color(value: &LitStr) -> syn::Result<[u8; 4]> {
+	let text = value.value();
+	let digits = text
+		.strip_prefix("0x")
+		.or_else(|| text.strip_prefix('#'))
+		.filter(|digits| {
+			matches!(digits.len(), 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+		})
+		.ok_or_else(|| {
+			syn::Error::new_spanned(
+				value,
+				"expected a hex color like \"0xFF8800\" or \"#FF8800\", optionally with alpha",
+			)
+		})?;
+	let byte = |index: usize| u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).unwrap();
+	Ok([
+		byte(0),
+		byte(1),
+		byte(2),
+		if digits.len() == 8 { byte(3) } else { 255 },
+	])
+}
+
+/// The arguments of `slider(<range>, <options>...)`, or of a bare `slider`.
+struct SliderArgs {
+	/// The `RangeInclusive` the slider spans, all values of the integer type if not given.
+	range: Option<Expr>,
+	/// Whether to fill the track up to the handle.
+	fill: bool,
+	/// Whether to clamp the field into the range before rendering the slider.
+	clamp: bool,
+	/// The label egui attaches to the slider itself, after its value, if any.
+	text: Option<LitStr>,
+}
+
+impl Parse for SliderArgs {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let range = input.parse()?;
+		let mut fill = false;
+		let mut clamp = false;
+		let mut text = None;
+		while !input.is_empty() {
+			input.parse::<Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			let option: Ident = input.parse()?;
+			if option == "fill" {
+				fill = true;
+			} else if option == "clamp" {
+				clamp = true;
+			} else if option == "text" {
+				input.parse::<Token![=]>()?;
+				text = Some(input.parse()?);
+			} else {
+				return Err(syn::Error::new_spanned(option, "unsupported slider option"));
+			}
+		}
+		Ok(Self {
+			range: Some(range),
+			fill,
+			clamp,
+			text,
+		})
+	}
+}
+
+impl SliderArgs {
+	/// The statements binding the range of the slider to `range`, and clamping the place
+	/// into it if asked to, for a field of type `ty`.
+	///
+	/// The range is evaluated before the row, every frame, so it can borrow `self`.
+	fn setup_tokens(&self, place: &TokenStream2, ty: &Type) -> syn::Result<TokenStream2> {
+		let range = self.range_tokens(ty)?;
+		let clamp = self.clamp_tokens(place);
+		Ok(quote! {
+			#range
+			#clamp
+		})
+	}
+
+	/// The statement binding the range of the slider to `range`, for a field of type `ty`.
+	///
+	/// Without a range the slider spans `<ty>::MIN..=<ty>::MAX`, which only makes sense
+	/// for integers, so a float field is an error.
+	fn range_tokens(&self, ty: &Type) -> syn::Result<TokenStream2> {
+		let range = match &self.range {
+			Some(range) => {
+				check_int_range(range, ty)?;
+				quote!(#range)
+			}
+			None if is_float(ty) => {
+				return Err(syn::Error::new_spanned(
+					ty,
+					"a slider on a float field needs a range, e.g. `slider(0.0..=1.0)`",
+				));
+			}
+			None => quote!(<#ty>::MIN..=<#ty>::MAX),
+		};
+		Ok(quote!(let range = #range;))
+	}
+
+	/// The statement clamping the place into `range`, binding `clamped` to whether it had
+	/// to, if asked to.
+	fn clamp_tokens(&self, place: &TokenStream2) -> Option<TokenStream2> {
+		self.clamp.then(|| {
+			quote! {
+				let clamped = {
+					let (start, end) = (*range.start(), *range.end());
+					if #place < start {
+						#place = start;
+						true
+					} else if #place > end {
+						#place = end;
+						true
+					} else {
+						false
+					}
+				};
+			}
+		})
+	}
+
+	/// The `egui::Slider` editing the given place over the `range` bound by the setup,
+	/// evaluating to its `Response`.
+	fn widget_tokens(
+		&self,
+		egui: &Path,
+		place: &TokenStream2,
+		raw: Option<&TokenStream2>,
+	) -> TokenStream2 {
+		let fill = self.fill.then(|| quote!(.trailing_fill(true)));
+		let text = self.text.as_ref().map(|text| quote!(.text(#text)));
+		let slider = quote!(ui.add(#egui::Slider::new(&mut #place, range) #fill #text #raw));
+		if !self.clamp {
+			return slider;
+		}
+		quote! {
+			{
+				let mut response = #slider;
+				if clamped {
+					response.mark_changed();
+				}
+				response
+			}
+		}
+	}
+}
+
+/// The arguments of `drag(clamp_range = <range>, clamp_existing)`, or of a bare `drag`.
+#[derive(Default)]
+struct DragArgs {
+	/// The `RangeInclusive` dragging the value stays in, if any.
+	clamp_range: Option<Expr>,
+	/// Where `clamp_existing` was given, if it was. The range then also applies to typed
+	/// values and to the value of the field itself.
+	clamp_existing: Option<Span>,
+}
+
+impl DragArgs {
+	/// The statement binding the range to `range`, for a field of type `ty`, if there is one.
+	fn setup_tokens(&self, ty: &Type) -> syn::Result<TokenStream2> {
+		let Some(range) = &self.clamp_range else {
+			return Ok(TokenStream2::new());
+		};
+		check_int_range(range, ty)?;
+		Ok(quote!(let range = #range;))
+	}
+
+	/// The `egui::DragValue` editing the given place, evaluating to its `Response`.
+	///
+	/// With `clamp_existing` egui clamps everything to the range. Otherwise only values set
+	/// by dragging are clamped, so a value typed in stays as it is, even out of the range.
+	fn widget_tokens(
+		&self,
+		egui: &Path,
+		place: &TokenStream2,
+		raw: Option<&TokenStream2>,
+	) -> TokenStream2 {
+		match (&self.clamp_range, self.clamp_existing) {
+			(None, _) => quote!(ui.add(#egui::DragValue::new(&mut #place) #raw)),
+			(Some(_), Some(_)) => {
+				quote!(ui.add(#egui::DragValue::new(&mut #place).clamp_range(range) #raw))
+			}
+			(Some(_), None) => quote! {
+				{
+					let response = ui.add(#egui::DragValue::new(&mut #place) #raw);
+					if response.dragged() {
+						let (start, end) = (*range.start(), *range.end());
+						if #place < start {
+							#place = start;
+						} else if #place > end {
+							#place = end;
+						}
+					}
+					response
+				}
+			},
+		}
+	}
+}
+
+/// The textbox editing a `String` or `PathBuf` path, followed by a "Browse…" button picking it
+/// with `rfd` if the feature is enabled, evaluating to the union of their `Response`s.
+fn file_tokens(egui: &Path, place: &TokenStream2, ty: &Type) -> TokenStream2 {
+	let is_path_buf = matches!(
+		ty,
+		Type::Path(TypePath { path, .. })
+			if path.segments.last().is_some_and(|segment| segment.ident == "PathBuf")
+	);
+	// A `PathBuf` has no text to edit in place, so go through a `String` and back.
+	let (text, write_text, write_path) = if is_path_buf {
+		(
+			quote! {
+				let mut buffer = #place.display().to_string();
+				let text = &mut buffer;
+			},
+			quote!(#place = <#ty>::from(buffer);),
+			quote!(#place = path;),
+		)
+	} else {
+		(
+			quote!(let text = &mut #place;),
+			quote!(),
+			quote!(#place = path.display().to_string();),
+		)
+	};
+	let browse = if cfg!(feature = "rfd") {
+		quote! {
+			let mut browse = ui.button("Browse…");
+			if browse.clicked() {
+				if let Some(path) = ::rfd::FileDialog::new().pick_file() {
+					#write_path
+					browse.mark_changed();
+				}
+			}
+		}
+	} else {
+		quote! {
+			let browse = ui
+				.add_enabled(false, #egui::Button::new("Browse…"))
+				.on_disabled_hover_text("Enable the `rfd` feature of bevy_egui_controls to browse for files");
+		}
+	};
+	quote! {
+		{
+			#text
+			let response = ui.add(#egui::TextEdit::singleline(text));
+			if response.changed() {
+				#write_text
+			}
+			#browse
+			response | browse
+		}
+	}
+}
+
+/// The textbox editing the value of type `ty` at `place` through a scratch string, evaluating
+/// to its `Response`.
+///
+/// The text is kept in egui's temp data while it is being edited, so it may be invalid in the
+/// meantime. Invalid text is painted in the error color, with the parse error as hover text,
+/// and does not count as a change. The text follows the field again once focus moves on.
+fn addr_tokens(egui: &Path, place: &TokenStream2, ty: &Type) -> TokenStream2 {
+	let salt = format!("addr {place}");
+	quote! {
+		{
+			let id = ui.make_persistent_id(#salt);
+			let mut text = ui
+				.memory(|memory| memory.has_focus(id))
+				.then(|| ui.data_mut(|data| data.get_temp::<::std::string::String>(id)))
+				.flatten()
+				.unwrap_or_else(|| #place.to_string());
+			let error = text.parse::<#ty>().err();
+			let color = error.as_ref().map(|_| ui.visuals().error_fg_color);
+			let mut response = ui.add(#egui::TextEdit::singleline(&mut text).id(id).text_color_opt(color));
+			match text.parse::<#ty>() {
+				Ok(value) if response.changed() => #place = value,
+				Ok(_) => {}
+				Err(_) => response.changed = false,
+			}
+			ui.data_mut(|data| data.insert_temp(id, text));
+			match error {
+				Some(error) => response.on_hover_text(error.to_string()),
+				None => response,
+			}
+		}
+	}
+}
+
+/// The arguments of `sliders(<range>, <options>...)`, rendering a slider per element of an array.
+struct SlidersArgs {
+	/// The `RangeInclusive` every slider spans.
+	range: Expr,
+	/// Whether the sliders are vertical, like the bands of an equalizer.
+	vertical: bool,
+	/// Whether the sliders are logarithmic.
+	logarithmic: bool,
+	/// The step every slider snaps to, if any.
+	step: Option<Expr>,
+	/// The labels under the sliders, in order. Sliders past the end are labelled with their index.
+	labels: Option<syn::ExprArray>,
+}
+
+impl Parse for SlidersArgs {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut args = Self {
+			range: input.parse()?,
+			vertical: false,
+			logarithmic: false,
+			step: None,
+			labels: None,
+		};
+		while !input.is_empty() {
+			input.parse::<Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			let option: Ident = input.parse()?;
+			if option == "vertical" {
+				args.vertical = true;
+			} else if option == "logarithmic" {
+				args.logarithmic = true;
+			} else if option == "step" {
+				input.parse::<Token![=]>()?;
+				args.step = Some(input.parse()?);
+			} else if option == "labels" {
+				input.parse::<Token![=]>()?;
+				args.labels = Some(input.parse()?);
+			} else {
+				return Err(syn::Error::new_spanned(
+					option,
+					"unsupported sliders option",
+				));
+			}
+		}
+		Ok(args)
+	}
+}
+
+impl SlidersArgs {
+	/// The sliders editing every element of the array at the given place side by side,
+	/// each over the `range` bound by the setup and labelled underneath, evaluating to the
+	/// union of their `Response`s.
+	fn widget_tokens(&self, egui: &Path, place: &TokenStream2) -> TokenStream2 {
+		let vertical = self.vertical.then(|| quote!(.vertical()));
+		let logarithmic = self.logarithmic.then(|| quote!(.logarithmic(true)));
+		let step = self.step.as_ref().map(|step| quote!(.step_by(#step)));
+		let labels = match &self.labels {
+			Some(labels) => quote!(&#labels),
+			None => quote!(&[]),
+		};
+		let slider = fold_response(quote! {
+			ui.vertical(|ui| {
+				let response = ui.add(
+					#egui::Slider::new(value, range.clone())
+						#vertical
+						#logarithmic
+						#step
+				);
+				match labels.get(index) {
+					Some(label) => ui.small(*label),
+					None => ui.small(index.to_string()),
+				};
+				response
+			})
+			.inner
+		});
+		quote! {
+			{
+				let labels: &[&str] = #labels;
+				let #egui::InnerResponse { inner, response } = ui.horizontal(|ui| {
+					let mut widgets: Option<#egui::Response> = None;
+					for (index, value) in #place.iter_mut().enumerate() {
+						#slider
+					}
+					widgets
+				});
+				match inner {
+					Some(widgets) => response | widgets,
+					None => response,
+				}
+			}
+		}
+	}
+}
+
+/// The arguments of `textbox(max_len = <usize>, width = <f32>)`, all optional.
+#[derive(Default)]
+struct TextboxArgs {
+	/// The number of characters the text is truncated to after every edit.
+	max_len: Option<Expr>,
+	/// The desired width of the text edit in points.
+	width: Option<Expr>,
+	/// The `egui::Id` of the text edit, derived from another field with `id_from`, if any.
+	id: Option<TokenStream2>,
+}
+
+impl TextboxArgs {
+	/// The single line `egui::TextEdit` editing the given place, followed by a character count
+	/// if the length is limited, evaluating to the `Response` of the text edit.
+	fn widget_tokens(
+		&self,
+		egui: &Path,
+		place: &TokenStream2,
+		raw: Option<&TokenStream2>,
+	) -> TokenStream2 {
+		let width = self
+			.width
+			.as_ref()
+			.map(|width| quote!(.desired_width(#width)));
+		let id = self.id.as_ref().map(|id| quote!(.id(#id)));
+		let text_edit =
+			quote!(ui.add(#egui::TextEdit::singleline(&mut #place).hint_text("") #width #id #raw));
+		let Some(max_len) = &self.max_len else {
+			return text_edit;
+		};
+		// Truncate at a char boundary, `String::truncate` panics in the middle of one.
+		quote! {
+			{
+				let max_len: usize = #max_len;
+				let response = #text_edit;
+				if response.changed() {
+					if let Some((end, _)) = #place.char_indices().nth(max_len) {
+						#place.truncate(end);
+					}
+				}
+				ui.small(format!("{}/{}", #place.chars().count(), max_len));
+				response
+			}
+		}
+	}
+}
+
+/// The arguments of `plot(height = <f32>, label = "...")`, all optional.
+#[derive(Default)]
+struct PlotArgs {
+	/// The height of the plot in points, defaults to `80.0`.
+	height: Option<Expr>,
+	/// The name of the line, shown in a legend.
+	label: Option<LitStr>,
+}
+
+impl PlotArgs {
+	/// The read-only `egui::plot::Plot` of the values at the given place,
+	/// evaluating to the `Response` of the plot.
+	fn widget_tokens(&self, egui: &Path, place: &TokenStream2) -> TokenStream2 {
+		let id_source = place.to_string();
+		let height = match &self.height {
+			Some(height) => quote!(#height),
+			None => quote!(80.0),
+		};
+		let (name, legend) = match &self.label {
+			Some(label) => (
+				quote!(.name(#label)),
+				quote!(.legend(#egui::plot::Legend::default())),
+			),
+			None => (TokenStream2::new(), TokenStream2::new()),
+		};
+		quote! {
+			{
+				let points: #egui::plot::PlotPoints = #place
+					.iter()
+					.enumerate()
+					.map(|(index, value)| [index as f64, f64::from(*value)])
+					.collect();
+				let line = #egui::plot::Line::new(points) #name;
+				#egui::plot::Plot::new(#id_source)
+					.height(#height)
+					.allow_drag(false)
+					.allow_zoom(false)
+					.allow_scroll(false)
+					.allow_boxed_zoom(false)
+					.allow_double_click_reset(false)
+					#legend
+					.show(ui, |plot| plot.line(line))
+					.response
+			}
+		}
+	}
+}
+
+/// Find the `#[control]` attribute of a field.
+///
+/// A field may have at most one, since every widget is bound to the same `&mut self.field`
+/// and would otherwise silently render twice.
+fn control_attr(field: &Field) -> syn::Result<Option<&Attribute>> {
+	let mut attrs = field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("control"));
+	let first = attrs.next();
+	if let Some(duplicate) = attrs.next() {
+		return Err(syn::Error::new_spanned(
+			duplicate,
+			"field has multiple control attributes, combine them into a single `#[control(...)]`",
+		));
+	}
+	Ok(first)
+}
+
+/// The expression rendering the widget editing a field into `ui`,
+/// evaluating to the widget's `Response`.
+///
+/// Widgets are either added through `ui.add` or, for widgets that only exist as
+/// `Ui` methods, rendered by calling the method directly.
+///
+/// `place` is the place expression of the value of type `ty` the widget edits,
+/// e.g. `self.radius`, and `raw` the builder calls of `raw(.method()...)` appended
+/// to widgets passed to `ui.add`.
+fn widget_tokens(
+	kind: &WidgetKind,
+	ty: &Type,
+	place: &TokenStream2,
+	raw: Option<&TokenStream2>,
+	args: &ContainerArgs,
+) -> syn::Result<TokenStream2> {
+	let egui = &args.egui_crate;
+	if let Some(raw) = raw.filter(|_| !kind.is_builder()) {
+		return Err(syn::Error::new_spanned(
+			raw,
+			"raw builder calls need a widget passed to `ui.add`, i.e. `slider`, `textbox`, `bool`, `drag` or `angle_deg`",
+		));
+	}
+	Ok(match kind {
+		WidgetKind::Slider(slider) => slider.widget_tokens(egui, place, raw),
+		WidgetKind::Textbox(textbox) => textbox.widget_tokens(egui, place, raw),
+		WidgetKind::Toggle(text) => quote!(ui.toggle_value(&mut #place, #text)),
+		WidgetKind::ListSlider(_) => {
+			unreachable!("list sliders become lists once the field is known")
+		}
+		WidgetKind::Tristate([none, on, off]) => quote! {
+			ui.horizontal(|ui| {
+				ui.selectable_value(&mut #place, None, #none)
+					| ui.selectable_value(&mut #place, Some(true), #on)
+					| ui.selectable_value(&mut #place, Some(false), #off)
+			})
+			.inner
+		},
+		// Checking a variant appends it, so the `Vec` keeps the order they were checked in and
+		// never holds one twice, unchecking removes every copy of it.
+		WidgetKind::Multiselect => {
+			let element = match ty {
+				Type::Path(_) => element_type(ty),
+				_ => None,
+			}
+			.ok_or_else(|| {
+				syn::Error::new_spanned(ty, "multiselect expects a `Vec` of a unit-only enum")
+			})?;
+			quote! {
+				ui.horizontal_wrapped(|ui| {
+					<#element>::VARIANTS
+						.iter()
+						.zip(<#element>::VARIANT_LABELS)
+						.map(|(variant, label)| {
+							let mut checked = #place.contains(variant);
+							let response = ui.checkbox(&mut checked, *label);
+							if response.changed() {
+								match checked {
+									true => #place.push(::std::clone::Clone::clone(variant)),
+									false => #place.retain(|item| item != variant),
+								}
 							}
-							// Collect every line of doc-comment.
-							doc_comments.push(raw_token.trim().to_string());
+							response
+						})
+						.reduce(|all, response| all | response)
+						.expect("an enum deriving `ControlPanel` has variants")
+				})
+				.inner
+			}
+		}
+		WidgetKind::Button(text) => quote! {
+			{
+				// Clicking sets the field, so it counts as a change, e.g. for `on_change`.
+				let mut response = ui.button(#text);
+				#place = response.clicked();
+				if #place {
+					response.mark_changed();
+				}
+				response
+			}
+		},
+		WidgetKind::Bool => quote! {
+			ui.add(#egui::Checkbox::without_text(&mut #place) #raw)
+		},
+		WidgetKind::Color => {
+			if !is_bevy_color(ty) {
+				return Err(syn::Error::new_spanned(
+					ty,
+					"color expects a bevy `Color` field",
+				));
+			}
+			// `Color32` stores sRGBA bytes, so convert via the sRGB components
+			// and only write back when the user actually edited the color,
+			// otherwise linear/HSL colors would be quantized every frame.
+			quote! {
+				{
+					let [r, g, b, a] = #place.as_rgba_f32();
+					let mut srgba = #egui::Color32::from_rgba_unmultiplied(
+						(r * 255.0).round() as u8,
+						(g * 255.0).round() as u8,
+						(b * 255.0).round() as u8,
+						(a * 255.0).round() as u8,
+					);
+					let response = ui.color_edit_button_srgba(&mut srgba);
+					if response.changed() {
+						let [r, g, b, a] = srgba.to_srgba_unmultiplied();
+						#place = <#ty>::rgba_u8(r, g, b, a);
+					}
+					response
+				}
+			}
+		}
+		WidgetKind::Drag(drag) => drag.widget_tokens(egui, place, raw),
+		WidgetKind::Addr => addr_tokens(egui, place, ty),
+		WidgetKind::Readonly { via, since, fmt } => {
+			let value = match via {
+				Some(member) => quote!(#place.#member),
+				None => quote!(#place),
+			};
+			match (since, fmt) {
+				// Nothing else repaints the panel as time passes, so ask for it.
+				(true, _) => quote! {
+					{
+						ui.ctx().request_repaint_after(::std::time::Duration::from_millis(100));
+						ui.label(::std::format!("{:.1}s ago", #value.elapsed().as_secs_f32()))
+					}
+				},
+				(false, Some(fmt)) => quote!(ui.label(::std::format!(#fmt, #value))),
+				// Entities only implement `Debug`, which shows their index and generation.
+				(false, None) if via.is_none() && is_entity(ty) => {
+					quote!(ui.label(::std::format!("{:?}", #value)))
+				}
+				(false, None) => quote!(ui.label(#value.to_string())),
+			}
+		}
+		WidgetKind::EntityPicker(_) => {
+			let salt = format!("entity picker {place}");
+			quote! {
+				{
+					let selected = candidates
+						.iter()
+						.find(|(entity, _)| *entity == #place)
+						.map(|(_, name)| name.clone())
+						.unwrap_or_else(|| ::std::format!("{:?}", #place));
+					let combo = #egui::ComboBox::from_id_source(#salt)
+						.selected_text(selected)
+						.show_ui(ui, |ui| {
+							candidates
+								.iter()
+								.map(|(entity, name)| ui.selectable_value(&mut #place, *entity, name.as_str()))
+								.reduce(|all, response| all | response)
+						});
+					let mut response = combo.response;
+					if combo.inner.flatten().is_some_and(|picked| picked.changed()) {
+						response.mark_changed();
+					}
+					response
+				}
+			}
+		}
+		WidgetKind::AngleDeg { wrap: false } => quote! {
+			ui.add(#egui::Slider::new(&mut #place, 0.0..=360.0).suffix("°") #raw)
+		},
+		// The value may be out of the range to begin with, or leave it through the slider's
+		// value box, so it is wrapped back both before and after the slider.
+		WidgetKind::AngleDeg { wrap: true } => quote! {
+			{
+				let before = #place;
+				#place = #place.rem_euclid(360.0);
+				let mut response = ui.add(
+					#egui::Slider::new(&mut #place, 0.0..=360.0)
+						.suffix("°")
+						.clamp_to_range(false)
+						#raw
+				);
+				#place = #place.rem_euclid(360.0);
+				if #place != before {
+					response.mark_changed();
+				}
+				response
+			}
+		},
+		WidgetKind::Nested => quote!(#place.ui(ui)),
+		WidgetKind::Gradient => gradient_tokens(egui, place),
+		WidgetKind::Plot(plot) => plot.widget_tokens(egui, place),
+		WidgetKind::With(function) => quote!(#function(&mut #place, ui)),
+		WidgetKind::Raw(widget) => quote!(ui.add(#widget #raw)),
+		WidgetKind::Sliders(sliders) => sliders.widget_tokens(egui, place),
+		WidgetKind::File => file_tokens(egui, place, ty),
+		WidgetKind::List(kind, ty) => {
+			let item = widget_tokens(kind, ty, &quote!((*item)), None, args)?;
+			// Sliders share the range bound by the setup, each clamping its own element.
+			let setup = match &**kind {
+				WidgetKind::Slider(slider) => {
+					let clamp = slider.clamp_tokens(&quote!((*item)));
+					quote! {
+						let range = range.clone();
+						#clamp
+					}
+				}
+				_ => TokenStream2::new(),
+			};
+			// Every item gets its own id, e.g. for the gradient selection and help popups
+			// keyed by their place, which is the same for all items.
+			quote! {
+				{
+					let list = ui.vertical(|ui| {
+						let mut items: Option<#egui::Response> = None;
+						for (index, item) in #place.iter_mut().enumerate() {
+							#setup
+							let response = ui.push_id(index, |ui| {
+								ui.horizontal(|ui| {
+									ui.small(index.to_string());
+									#item
+								})
+								.inner
+							})
+							.inner;
+							items = Some(match items {
+								Some(items) => items | response,
+								None => response,
+							});
 						}
+						items
+					});
+					match list.inner {
+						Some(items) => list.response | items,
+						None => list.response,
 					}
-				});
+				}
+			}
+		}
+		WidgetKind::Optional(kind, ty) => {
+			let value = widget_tokens(kind, ty, &quote!((*value)), None, args)?;
+			// Checking the box fills in the default value, so it can be edited right away.
+			quote! {
+				ui.horizontal(|ui| {
+					let mut some = #place.is_some();
+					let mut response = ui.add(#egui::Checkbox::without_text(&mut some));
+					if response.changed() {
+						#place = some.then(<#ty as ::std::default::Default>::default);
+					}
+					if let Some(value) = &mut #place {
+						response |= #value;
+					}
+					response
+				})
+				.inner
+			}
+		}
+		WidgetKind::SliderDuration(_) => {
+			if !is_duration(ty) {
+				return Err(syn::Error::new_spanned(
+					ty,
+					"slider_duration expects a `Duration` field",
+				));
+			}
+			// `Duration::from_secs_f32` panics on negative seconds, so keep the range
+			// non-negative, and only write back edits to keep the full precision otherwise.
+			quote! {
+				{
+					let start = range.start().max(0.0);
+					let end = range.end().max(start);
+					let mut seconds = #place.as_secs_f32().clamp(start, end);
+					let response = ui.add(#egui::Slider::new(&mut seconds, start..=end).suffix(" s"));
+					if response.changed() {
+						#place = <#ty>::from_secs_f32(seconds.max(0.0));
+					}
+					response
+				}
 			}
-		});
-
-		if doc_comments.is_empty() {
-			return "No doc comment found".to_string();
 		}
-		doc_comments.join(" ")
 	})
 }
 
-/// Parse fields for the widgets to generate from the `#[control]` field attributes.
-fn parse_widgets_from_fields(fields: &Fields) -> impl Iterator<Item = TokenStream2> + '_ {
-	fields.iter().flat_map(|field| {
-		let name = field.ident.clone().unwrap();
-		field.attrs.iter().filter_map(move |attr| {
-			if let Meta::List(MetaList { path, tokens, .. }) = &attr.meta {
-				if path.into_token_stream().to_string() == "control" {
-					let mut token_iter = tokens.clone().into_iter();
-					if let Some(proc_macro2::TokenTree::Ident(ident)) = token_iter.next() {
-						if ident == "slider" {
-							let proc_macro2::TokenTree::Group(group) = token_iter
-								.next()
-								.expect("slider to be provided a InclusiveRange prop")
-							else {
-								panic!("slider expects an InclusiveRange prop.");
-							};
-							let stream = group.stream();
-							return Some(quote!(
-									::bevy_egui::egui::Slider::new(&mut self.#name, #stream)
-							));
-						} else if ident == "textbox" {
-							return Some(quote!(
-									::bevy_egui::egui::TextEdit::singleline(&mut self.#name).hint_text("")
-							));
-						} else if ident == "bool" {
-							return Some(quote! {
-									::bevy_egui::egui::Checkbox::without_text(&mut self.#name)
-							});
+/// The gradient editor for a `Vec<([f32; 4], f32)>` of linear RGBA colors and their
+/// positions in `0.0..=1.0`, evaluating to the `Response` of the gradient bar.
+///
+/// The bar has a draggable handle per stop, clicking one selects it for the color picker
+/// and the remove button. The selection is kept in egui's memory, keyed by `place`, and
+/// the stops are kept sorted by position, so the selection follows its stop when reordered.
+fn gradient_tokens(egui: &Path, place: &TokenStream2) -> TokenStream2 {
+	let id_source = place.to_string();
+	quote! {
+		ui.vertical(|ui| {
+			let stops = &mut #place;
+			let id = ui.id().with(#id_source);
+			let mut selected = ui
+				.data(|data| data.get_temp::<Option<usize>>(id))
+				.flatten()
+				.filter(|&index| index < stops.len());
+			let size = #egui::vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
+			let (rect, mut response) = ui.allocate_exact_size(size, #egui::Sense::hover());
+			let color = |[r, g, b, a]: [f32; 4]| -> #egui::Color32 {
+				#egui::Rgba::from_rgba_unmultiplied(r, g, b, a).into()
+			};
+			let x = |position: f32| rect.left() + position.clamp(0.0, 1.0) * rect.width();
+
+			for (index, (_, position)) in stops.iter_mut().enumerate() {
+				let handle = #egui::Rect::from_center_size(
+					#egui::pos2(x(*position), rect.bottom()),
+					#egui::vec2(8.0, 8.0),
+				);
+				let handle = ui.interact(handle, id.with(index), #egui::Sense::click_and_drag());
+				if handle.clicked() || handle.drag_started() {
+					selected = Some(index);
+				}
+				if handle.dragged() {
+					if let Some(pointer) = handle.interact_pointer_pos() {
+						*position = ((pointer.x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+						response.mark_changed();
+					}
+				}
+			}
+
+			ui.horizontal(|ui| {
+				if ui.button("+").clicked() {
+					let stop = selected.map_or([1.0; 4], |index| stops[index].0);
+					stops.push((stop, 0.5));
+					selected = Some(stops.len() - 1);
+					response.mark_changed();
+				}
+				if let Some(index) = selected {
+					if ui.button("-").clicked() {
+						stops.remove(index);
+						selected = None;
+						response.mark_changed();
+					} else if ui.color_edit_button_rgba_unmultiplied(&mut stops[index].0).changed() {
+						response.mark_changed();
+					}
+				}
+			});
+
+			if !stops.windows(2).all(|pair| pair[0].1 <= pair[1].1) {
+				let selected_stop = selected.map(|index| stops[index]);
+				stops.sort_by(|a, b| a.1.total_cmp(&b.1));
+				selected = selected_stop.and_then(|stop| stops.iter().position(|other| *other == stop));
+				response.mark_changed();
+			}
+			ui.data_mut(|data| data.insert_temp(id, selected));
+
+			if ui.is_rect_visible(rect) {
+				let painter = ui.painter();
+				if let (Some(first), Some(last)) = (stops.first(), stops.last()) {
+					// Extend the outer stops to the ends of the bar.
+					let mut points = vec![(rect.left(), color(first.0))];
+					points.extend(stops.iter().map(|(stop, position)| (x(*position), color(*stop))));
+					points.push((rect.right(), color(last.0)));
+					let mut mesh = #egui::Mesh::default();
+					for (index, (x, color)) in points.iter().enumerate() {
+						mesh.colored_vertex(#egui::pos2(*x, rect.top()), *color);
+						mesh.colored_vertex(#egui::pos2(*x, rect.bottom()), *color);
+						if index > 0 {
+							let index = index as u32 * 2;
+							mesh.add_triangle(index - 2, index - 1, index);
+							mesh.add_triangle(index - 1, index, index + 1);
 						}
-						return None;
 					}
+					painter.add(mesh);
+				}
+				let visuals = ui.visuals();
+				painter.rect_stroke(rect, 0.0, visuals.widgets.noninteractive.bg_stroke);
+				for (index, (stop, position)) in stops.iter().enumerate() {
+					let stroke = if selected == Some(index) {
+						visuals.selection.stroke
+					} else {
+						visuals.widgets.inactive.fg_stroke
+					};
+					painter.circle(#egui::pos2(x(*position), rect.bottom()), 4.0, color(*stop), stroke);
 				}
 			}
-			None
+			response
 		})
-	})
+		.inner
+	}
+}
+
+/// Parse fields for the widgets to generate from the `#[control]` field attributes,
+/// in the order of definition.
+///
+/// `place` maps the member of a field to its place expression, e.g. `self.radius`.
+fn parse_widgets_from_fields(
+	fields: &Fields,
+	args: &ContainerArgs,
+	place: impl Fn(&Member) -> TokenStream2,
+) -> syn::Result<Vec<FieldControl>> {
+	let mut controls = vec![];
+	for (index, field) in fields.iter().enumerate() {
+		let Some(attr) = control_attr(field)? else {
+			continue;
+		};
+		let field_ty = ungroup(&field.ty);
+		if is_marker(field_ty) {
+			continue;
+		}
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(Index::from(index)),
+		};
+		let control = ControlArgs::from_attr(attr)?;
+		if control.skip.is_some() {
+			continue;
+		}
+		let id_from = control
+			.id_from
+			.as_ref()
+			.map(|(_, from)| place(&Member::Named(from.clone())));
+		let place = place(&member);
+		// A locked field is edited through the guard, so the widget and its setup
+		// are rendered inside the match on the lock, see below.
+		let locked = match control.lock {
+			true => Some(locked_type(field_ty).ok_or_else(|| {
+				syn::Error::new_spanned(&field.ty, "lock expects a `Mutex` or `RwLock` field")
+			})?),
+			false => None,
+		};
+		let (ty, edited) = match &locked {
+			Some((ty, _)) => (*ty, quote!((*guard))),
+			None => (field_ty, place.clone()),
+		};
+		// The number inside a `Wrapping` or `Saturating` is public, so it is edited in place.
+		let (ty, edited) = match wrapped_number_type(ty) {
+			Some(number) => (number, quote!(#edited.0)),
+			None => (ty, edited),
+		};
+		let mut kind = match control.widget {
+			Some(kind) => {
+				check_field_type(&kind, field, ty)?;
+				kind
+			}
+			None => infer_widget(ty),
+		};
+		kind = match kind {
+			WidgetKind::ListSlider(slider) => {
+				let element = element_type(ty).ok_or_else(|| {
+					syn::Error::new_spanned(ty, "list_slider expects an array or `Vec` field")
+				})?;
+				WidgetKind::List(
+					Box::new(WidgetKind::Slider(slider)),
+					Box::new(element.clone()),
+				)
+			}
+			kind => kind,
+		};
+		// The id hashes the value of the other field, so the state of the text edit follows
+		// the value rather than the position of the panel, e.g. in a reordered list.
+		if let Some(((value, from), id_from)) = control.id_from.as_ref().zip(id_from) {
+			let WidgetKind::Textbox(textbox) = &mut kind else {
+				return Err(syn::Error::new_spanned(
+					value,
+					"`id_from` is only supported on `textbox` controls",
+				));
+			};
+			if !fields
+				.iter()
+				.any(|field| field.ident.as_ref() == Some(from))
+			{
+				return Err(syn::Error::new_spanned(
+					value,
+					format!("there is no field named `{from}` to derive the id from"),
+				));
+			}
+			let egui = &args.egui_crate;
+			let name = quote!(#member).to_string();
+			textbox.id = Some(quote! {
+				#egui::Id::new((::std::any::type_name::<Self>(), #name, &#id_from))
+			});
+		}
+		let (mut doc, doc_more) = match parse_doc_short(&field.attrs) {
+			_ if args.hide_docs => (None, None),
+			Some((short, more)) if args.short_docs => (Some(short), more),
+			_ => (
+				parse_doc_comment(&field.attrs).or_else(|| args.missing_doc.clone()),
+				None,
+			),
+		};
+		// A control asking for `help` gets its popup even if the other fields get icons.
+		let help_icon = args.help_icon && !control.help;
+		let help = (control.help || args.help || help_icon)
+			.then(|| parse_doc_text(&field.attrs))
+			.flatten();
+		// Wide toggles and buttons are labelled with the doc comment itself, so it is not
+		// repeated after them, unless it is in a help popup. Otherwise they fall back to the name.
+		let button = matches!(kind, WidgetKind::Button(_));
+		if let WidgetKind::Toggle(text) | WidgetKind::Button(text) = &mut kind {
+			let documented = !args.hide_docs && help.is_none();
+			*text = match parse_doc_comment(&field.attrs).filter(|_| documented) {
+				Some(doc) => doc,
+				None => humanize(&member),
+			};
+			doc = None;
+		}
+		let mut setup = match &kind {
+			WidgetKind::Slider(slider) => slider.setup_tokens(&edited, ty)?,
+			WidgetKind::Drag(drag) => drag.setup_tokens(ty)?,
+			// The candidates are copied out, so picking one can borrow the field mutably even if
+			// the method lends them out of `self`.
+			WidgetKind::EntityPicker(method) => quote! {
+				let candidates: ::std::vec::Vec<(_, ::std::string::String)> = self.#method().to_vec();
+			},
+			// The elements are clamped one by one, see `widget_tokens`.
+			WidgetKind::List(inner, element) => match &**inner {
+				WidgetKind::Slider(slider) => slider.range_tokens(element)?,
+				_ => TokenStream2::new(),
+			},
+			WidgetKind::SliderDuration(range) => quote! {
+				let range: ::std::ops::RangeInclusive<f32> = #range;
+			},
+			WidgetKind::Sliders(SlidersArgs { range, .. }) => {
+				if let Type::Array(array) = ty {
+					check_int_range(range, &array.elem)?;
+				}
+				quote! {
+					let range = #range;
+				}
+			}
+			_ => TokenStream2::new(),
+		};
+		let mut widget = widget_tokens(&kind, ty, &edited, control.raw.as_ref(), args)?;
+		if let Some([r, g, b, a]) = control.accent {
+			// The visuals are only changed in the scope of the widget, e.g. the filled part
+			// of a slider and its handle while it is hovered or dragged.
+			let egui = &args.egui_crate;
+			widget = quote! {
+				ui.scope(|ui| {
+					let accent = #egui::Color32::from_rgba_unmultiplied(#r, #g, #b, #a);
+					let visuals = ui.visuals_mut();
+					visuals.selection.bg_fill = accent;
+					visuals.widgets.hovered.bg_fill = accent;
+					visuals.widgets.active.bg_fill = accent;
+					#widget
+				})
+				.inner
+			};
+		}
+		if let Some((_, lock)) = &locked {
+			let egui = &args.egui_crate;
+			widget = quote! {
+				match #place.#lock() {
+					Ok(mut guard) => {
+						#setup
+						#widget
+					}
+					Err(_) => {
+						let color = ui.visuals().error_fg_color;
+						ui.add(#egui::Label::new(#egui::RichText::new("poisoned lock").color(color)))
+					}
+				}
+			};
+			setup = TokenStream2::new();
+		}
+		// Plots are read-only, so there is nothing to type into.
+		let editable = !matches!(kind, WidgetKind::Plot(_));
+		let request_focus = args.request_focus_first
+			&& editable
+			&& !controls
+				.iter()
+				.any(|control: &FieldControl| control.request_focus);
+		// A grid always has a name in its first column.
+		let label = match (args.labels_from_serde, args.grid) {
+			(true, _) => {
+				Some(parse_serde_rename(&field.attrs)?.unwrap_or_else(|| humanize(&member)))
+			}
+			(false, true) => Some(humanize(&member)),
+			(false, false) => None,
+		};
+		controls.push(FieldControl {
+			button,
+			locked: locked.is_some(),
+			name: quote!(#member).to_string(),
+			label,
+			setup,
+			widget,
+			member,
+			doc,
+			doc_more,
+			on_change: control.on_change,
+			debounce: control.debounce,
+			request_focus,
+			show_value: (control.show_value || args.show_values).then(|| place.clone()),
+			unit: control.unit,
+			validate: control.validate.map(|function| (place.clone(), function)),
+			help,
+			help_icon,
+			advanced: control.advanced,
+		});
+	}
+	Ok(controls)
+}
+
+/// The statements rendering the rows of the given controls in order, folding their
+/// `Response`s into `widgets`, followed by the `advanced` ones in a collapsed header.
+fn rows_tokens(controls: &[FieldControl], args: &ContainerArgs) -> TokenStream2 {
+	let egui = &args.egui_crate;
+	let row = |control: &FieldControl| {
+		let row = control.row_tokens(args);
+		let fold = fold_response(quote!(#row.inner));
+		quote!({ #fold })
+	};
+	// The advanced rows get their own grid, the header cannot go into a grid cell.
+	// Folding moves `widgets`, so it is moved through the closure and back out.
+	let columns: usize = if args.unit_column { 4 } else { 2 };
+	let grid = |salt: &str, rows: Vec<TokenStream2>| match args.grid {
+		true => quote! {
+			widgets = #egui::Grid::new(#salt)
+				.num_columns(#columns)
+				.show(ui, |ui| {
+					let mut widgets = widgets;
+					#(#rows)*
+					widgets
+				})
+				.inner;
+		},
+		false => quote!(#(#rows)*),
+	};
+	let rows = grid(
+		"controls",
+		controls
+			.iter()
+			.filter(|control| !control.advanced)
+			.map(row)
+			.collect(),
+	);
+	let advanced: Vec<_> = controls
+		.iter()
+		.filter(|control| control.advanced)
+		.map(row)
+		.collect();
+	if advanced.is_empty() {
+		return rows;
+	}
+	let advanced = grid("advanced controls", advanced);
+	let header = fold_response(quote!(advanced.header_response));
+	let body = fold_response(quote!(response));
+	quote! {
+		#rows
+		let advanced = #egui::CollapsingHeader::new("Advanced")
+			.default_open(false)
+			.show(ui, |ui| {
+				let mut widgets: Option<#egui::Response> = None;
+				#advanced
+				widgets
+			});
+		if let Some(response) = advanced.body_returned.flatten() {
+			#body
+		}
+		#header
+	}
+}
+
+/// With `#[controls(control_rects)]`, the `control_rect` method looking up the rect the
+/// widget of a field was last shown at by its name, given every name along with its id.
+fn control_rect_tokens(ids: &[(String, TokenStream2)], args: &ContainerArgs) -> TokenStream2 {
+	if !args.control_rects {
+		return TokenStream2::new();
+	}
+	let egui = &args.egui_crate;
+	let names = ids.iter().map(|(name, _)| name);
+	let ids = ids.iter().map(|(_, id)| id);
+	quote! {
+		/// The rect the widget of `field` was last shown at, if it has one and was shown,
+		/// e.g. to scroll to it with `ui.scroll_to_rect(rect, None)` or highlight it.
+		pub fn control_rect(
+			ctx: &#egui::Context,
+			field: &str,
+		) -> ::std::option::Option<#egui::Rect> {
+			match field {
+				#(#names => ctx.data(|data| data.get_temp(#ids)),)*
+				_ => ::std::option::Option::None,
+			}
+		}
+	}
+}
+
+/// The `ui_builder` method of a struct, along with the builder type it returns, rendering the
+/// rows of the given controls one at a time, in any order, with custom rows in between.
+///
+/// The rows are rendered by a private method on the struct, so they keep using `self` and
+/// `ui` as in the generated panel. They are never laid out in a grid, the custom rows could
+/// not fill its columns, and `advanced` rows are rendered in place like the others.
+fn builder_tokens(
+	input: &DeriveInput,
+	controls: &[FieldControl],
+	args: &ContainerArgs,
+) -> TokenStream2 {
+	let egui = &args.egui_crate;
+	let vis = &input.vis;
+	let struct_name = &input.ident;
+	let builder = format_ident!("{}ControlsBuilder", struct_name);
+	let plain = ContainerArgs {
+		grid: false,
+		widgets_right: false,
+		unit_column: false,
+		..args.clone()
+	};
+	let count = controls.len();
+	let indices = 0..count;
+	let names = controls.iter().map(|control| &control.name);
+	let rows = controls.iter().enumerate().map(|(index, control)| {
+		let request_focus = control.request_focus.then(|| args.request_focus_tokens());
+		let row = control.row_tokens(&plain);
+		quote! {
+			#index => {
+				#request_focus
+				#row.inner
+			}
+		}
+	});
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let mut generics = input.generics.clone();
+	generics.params.insert(0, parse_quote!('controls));
+	let (builder_impl_generics, builder_ty_generics, builder_where_clause) =
+		generics.split_for_impl();
+	let missing = format!("{struct_name} has no control named {{:?}}");
+	let builder_doc = format!(
+		"Renders the controls of [`{struct_name}`] one at a time, returned by \
+		 [`{struct_name}::ui_builder`]."
+	);
+	quote! {
+		impl #impl_generics #struct_name #ty_generics #where_clause {
+			/// A builder rendering the controls of the panel one at a time, e.g.
+			/// `panel.ui_builder(ui).field("speed").custom(|ui| { ui.separator(); }).rest()`
+			/// to put a custom row after `speed`, followed by every other field.
+			pub fn ui_builder<'controls>(
+				&'controls mut self,
+				ui: &'controls mut #egui::Ui,
+			) -> #builder #builder_ty_generics {
+				#builder {
+					panel: self,
+					ui,
+					rendered: [false; #count],
+					widgets: None,
+				}
+			}
+
+			/// The row of the `index`th control, evaluating to the widget's `Response`.
+			#[allow(unused_variables)]
+			fn __control_row(&mut self, ui: &mut #egui::Ui, index: usize) -> #egui::Response {
+				match index {
+					#(#rows)*
+					_ => ::std::unreachable!(),
+				}
+			}
+		}
+
+		#[doc = #builder_doc]
+		#vis struct #builder #builder_impl_generics #builder_where_clause {
+			panel: &'controls mut #struct_name #ty_generics,
+			ui: &'controls mut #egui::Ui,
+			rendered: [bool; #count],
+			widgets: ::std::option::Option<#egui::Response>,
+		}
+
+		impl #builder_impl_generics #builder #builder_ty_generics #builder_where_clause {
+			/// Render the row of the control of `field`.
+			///
+			/// # Panics
+			///
+			/// If `field` has no control.
+			pub fn field(mut self, field: &str) -> Self {
+				let index = match field {
+					#(#names => #indices,)*
+					_ => ::std::panic!(#missing, field),
+				};
+				self.row(index);
+				self
+			}
+
+			/// Render a custom row with `add`, between the rows of the controls around it.
+			pub fn custom(self, add: impl FnOnce(&mut #egui::Ui)) -> Self {
+				add(self.ui);
+				self
+			}
+
+			/// Render the rows of every control not rendered yet, in order, returning the
+			/// union of the `Response`s of every widget rendered by the builder.
+			pub fn rest(mut self) -> #egui::Response {
+				for index in 0..#count {
+					if !self.rendered[index] {
+						self.row(index);
+					}
+				}
+				match self.widgets {
+					Some(widgets) => widgets,
+					None => self.ui.allocate_response(#egui::Vec2::ZERO, #egui::Sense::hover()),
+				}
+			}
+
+			fn row(&mut self, index: usize) {
+				let response = self.panel.__control_row(self.ui, index);
+				self.rendered[index] = true;
+				self.widgets = Some(match self.widgets.take() {
+					Some(widgets) => widgets | response,
+					None => response,
+				});
+			}
+		}
+	}
+}
+
+/// With `#[controls(summary)]`, the `summary` method formatting the value of every struct
+/// field with its name, leaving out buttons and locked fields.
+fn summary_tokens(controls: &[FieldControl], args: &ContainerArgs) -> TokenStream2 {
+	let Some((format, _)) = args.summary else {
+		return TokenStream2::new();
+	};
+	let format = format!("{{}}: {format}");
+	let values = controls
+		.iter()
+		.filter(|control| !control.button && !control.locked)
+		.map(|control| {
+			let member = &control.member;
+			let name = control.label.clone().unwrap_or_else(|| humanize(member));
+			quote!(::std::format!(#format, #name, self.#member))
+		});
+	quote! {
+		/// The name and value of every field on a single line, e.g. for tooltips or logs.
+		pub fn summary(&self) -> ::std::string::String {
+			let values: ::std::vec::Vec<::std::string::String> = ::std::vec![#(#values),*];
+			values.join(", ")
+		}
+	}
+}
+
+/// With `#[controls(reset_field)]`, the `reset_field` method setting a struct field, named
+/// like in `control_rect`, back to its value in `Self::default()`. The bound is spanned at
+/// `reset_field`, so a type missing `Default` is reported there.
+fn reset_field_tokens(controls: &[FieldControl], args: &ContainerArgs) -> TokenStream2 {
+	let Some(span) = args.reset_field else {
+		return TokenStream2::new();
+	};
+	let bound = quote_spanned!(span=> Self: ::std::default::Default);
+	let arms = controls.iter().map(|control| {
+		let member = &control.member;
+		let name = &control.name;
+		quote! {
+			#name => {
+				self.#member = <Self as ::std::default::Default>::default().#member;
+				true
+			}
+		}
+	});
+	quote! {
+		/// Set `field` back to its value in `Self::default()`, returning whether the panel has
+		/// a field of that name, e.g. for a "Reset" entry in a context menu.
+		pub fn reset_field(&mut self, field: &str) -> bool
+		where
+			#bound,
+		{
+			match field {
+				#(#arms)*
+				_ => false,
+			}
+		}
+	}
+}
+
+/// The `validation_errors` method of a struct with `validate` controls, checking every
+/// validated field without rendering anything.
+fn validation_errors_tokens(controls: &[FieldControl]) -> TokenStream2 {
+	let checks: Vec<_> = controls
+		.iter()
+		.filter_map(|control| {
+			let (place, function) = control.validate.as_ref()?;
+			let name = &control.name;
+			Some(quote! {
+				if let ::std::result::Result::Err(error) = #function(&#place) {
+					errors.push((#name, error));
+				}
+			})
+		})
+		.collect();
+	if checks.is_empty() {
+		return TokenStream2::new();
+	}
+	quote! {
+		/// The name and error of every field whose `validate` function currently rejects its
+		/// value, in order, e.g. to disable a "Save" button while it is not empty.
+		pub fn validation_errors(&self) -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+			let mut errors = ::std::vec::Vec::new();
+			#(#checks)*
+			errors
+		}
+	}
+}
+
+/// The `clicked_action` method showing a struct panel and returning the name of the
+/// `button` clicked this frame, if any.
+fn clicked_action_tokens(controls: &[FieldControl], args: &ContainerArgs) -> TokenStream2 {
+	let ContainerArgs {
+		egui_crate: egui,
+		method,
+		..
+	} = args;
+	let buttons = controls
+		.iter()
+		.filter(|control| control.button)
+		.map(|control| {
+			let member = &control.member;
+			let name = &control.name;
+			quote! {
+				if self.#member {
+					return ::std::option::Option::Some(#name);
+				}
+			}
+		});
+	quote! {
+		#[must_use = "the panel is shown either way, use `show` to only show it"]
+		pub fn clicked_action(&mut self, ui: &mut #egui::Ui) -> ::std::option::Option<&'static str> {
+			self.#method(ui);
+			#(#buttons)*
+			::std::option::Option::None
+		}
+	}
+}
+
+/// The statement folding a widget's `Response` into the `widgets` local of the generated
+/// body, so the `Response` returned by `ui` reports interactions with any of its widgets.
+fn fold_response(response: TokenStream2) -> TokenStream2 {
+	quote! {
+		let response = #response;
+		widgets = Some(match widgets {
+			Some(widgets) => widgets | response,
+			None => response,
+		});
+	}
+}
+
+/// With the `inspector` feature, a constructor for the `bevy-inspector-egui` type data
+/// displaying the type through its generated `method`.
+fn inspector_tokens(method: &Ident) -> TokenStream2 {
+	if !cfg!(feature = "inspector") {
+		return TokenStream2::new();
+	}
+	let doc = format!(
+		"The `InspectorEguiImpl` displaying this type through [`Self::{method}`], to be inserted\n\
+		 into its registration in the `TypeRegistry`."
+	);
+	quote! {
+		#[doc = #doc]
+		pub fn inspector_egui_impl() -> ::bevy_inspector_egui::inspector_egui_impls::InspectorEguiImpl
+		where
+			Self: 'static,
+		{
+			::bevy_inspector_egui::inspector_egui_impls::InspectorEguiImpl::new(
+				|value, ui, _, _, _| {
+					value
+						.downcast_mut::<Self>()
+						.map_or(false, |value| value.#method(ui).changed())
+				},
+				|_, ui, _, _, _| {
+					ui.add_enabled(false, ::bevy_inspector_egui::egui::Label::new(::std::any::type_name::<Self>()));
+				},
+				|ui, _, _, _, _, _| {
+					ui.label(format!("{} doesn't support multi-editing.", ::std::any::type_name::<Self>()));
+					false
+				},
+			)
+		}
+	}
+}
+
+/// With the `bevy` feature, a constructor for the bevy system showing the type, as a
+/// resource, in an `egui::Window` of the primary window's egui context.
+fn bevy_system_tokens(egui: &Path, method: &Ident) -> TokenStream2 {
+	if !cfg!(feature = "bevy") {
+		return TokenStream2::new();
+	}
+	// The bound is higher-ranked so it is only checked when the method is used,
+	// a plain `Self: Resource` would be a trivially false bound on every other type.
+	quote! {
+		/// The system showing this resource in an `egui::Window` with the given title every frame,
+		/// to be added with e.g. `app.add_systems(Update, Settings::window_system("Settings"))`.
+		pub fn window_system(title: &'static str) -> ::bevy::ecs::system::BoxedSystem
+		where
+			for<'__system> Self: ::bevy::ecs::system::Resource,
+		{
+			::std::boxed::Box::new(::bevy::ecs::system::IntoSystem::into_system(
+				move |mut contexts: ::bevy_egui::EguiContexts,
+					  mut value: ::bevy::ecs::system::ResMut<Self>| {
+					#egui::Window::new(title).show(contexts.ctx_mut(), |ui| {
+						value.#method(ui);
+					});
+				},
+			))
+		}
+	}
+}
+
+/// The options of the `#[control(...)]` attributes of an enum variant.
+struct VariantArgs {
+	/// Whether `skip` was given, leaving the variant out of the selector.
+	skip: bool,
+	/// The label of the variant, as given to `label`, or the name of the variant.
+	label: String,
+}
+
+impl VariantArgs {
+	/// Parse every `#[control(...)]` attribute on the variant.
+	fn from_variant(variant: &Variant) -> syn::Result<Self> {
+		let mut args = Self {
+			skip: false,
+			label: variant.ident.to_string(),
+		};
+		for attr in variant
+			.attrs
+			.iter()
+			.filter(|attr| attr.path().is_ident("control"))
+		{
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("skip") {
+					args.skip = true;
+					Ok(())
+				} else if meta.path.is_ident("label") {
+					let value: LitStr = meta.value()?.parse()?;
+					args.label = value.value();
+					Ok(())
+				} else {
+					Err(meta.error("variants only support `skip` and `label = \"...\"`"))
+				}
+			})?;
+		}
+		Ok(args)
+	}
+}
+
+/// The pattern matching any value of a variant, regardless of the data it carries.
+fn variant_pattern(variant: &Variant) -> TokenStream2 {
+	let ident = &variant.ident;
+	match &variant.fields {
+		Fields::Named(_) => quote!(Self::#ident { .. }),
+		Fields::Unnamed(_) => quote!(Self::#ident(..)),
+		Fields::Unit => quote!(Self::#ident),
+	}
 }
 
-/// Expand the parsed struct into a [bevy_egui::egui::Grid] of three columns
-/// where the first column is the struct field name, the second column
-/// is the interactive form control, and the third field is the description
-/// of the field extracted from the doc comment.
-pub fn expand(input: DeriveInput) -> TokenStream {
+/// Expand the derive input into an inherent impl of the type, along with the builder type of
+/// a struct.
+///
+/// The impl holds the generated `ui` method, or the one named by `method`, rendering a row per
+/// field, i.e. its widget followed by its description, in the layout picked by `layout` and
+/// `align`. With `grid` or `unit_column` the names, widgets, units and descriptions go into
+/// columns of an `egui::Grid`, and with `collapsible` the whole body sits in a collapsing
+/// header. Enums render a selectable label per variant above the fields of the selected one.
+/// Next to it are `show`, `ui_framed` and `ui_changed`, and whichever of the other methods the
+/// `#[controls(...)]` options ask for.
+///
+/// Everything here works on `proc_macro2` and `syn` types only, errors included, so it runs
+/// outside of a macro invocation too. The derive converts the result and turns errors into
+/// `compile_error!`s.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+	let args = ContainerArgs::from_attrs(&input.attrs)?;
+	let layout = args.layout_tokens();
+	let description = args.description_tokens(&input.attrs);
+	let touch = args.touch_tokens();
+	let egui = &args.egui_crate;
+	let method = &args.method;
+	let inspector = inspector_tokens(method);
+	let track_dirty = args.track_dirty_tokens();
+	let request_focus = args.request_focus_tokens();
+	let bevy_system = bevy_system_tokens(egui, method);
+	let framed_doc = format!(
+		"[`Self::{method}`] inside `frame`, e.g. to give the panel a background and margins."
+	);
+	if let (Data::Enum(_), Some((_, span))) = (&input.data, args.summary) {
+		return Err(syn::Error::new(
+			span,
+			"`summary` is only supported on structs",
+		));
+	}
+	if let (Data::Enum(_), Some(span)) = (&input.data, args.reset_field) {
+		return Err(syn::Error::new(
+			span,
+			"`reset_field` is only supported on structs",
+		));
+	}
 	match &input.data {
 		Data::Struct(DataStruct { fields, .. }) => {
 			let struct_name = &input.ident;
-			let field_docs = parse_doc_comments_from_fields(fields);
-			let field_widgets = parse_widgets_from_fields(fields);
+			let controls = parse_widgets_from_fields(fields, &args, |member| quote!(self.#member))?;
+			let rows = rows_tokens(&controls, &args);
+			let ids: Vec<_> = controls
+				.iter()
+				.map(|control| (control.name.clone(), control.id_tokens(egui)))
+				.collect();
+			let control_rect = control_rect_tokens(&ids, &args);
+			let clicked_action = clicked_action_tokens(&controls, &args);
+			let summary = summary_tokens(&controls, &args);
+			let reset_field = reset_field_tokens(&controls, &args);
+			let validation_errors = validation_errors_tokens(&controls);
+			let builder = builder_tokens(&input, &controls, &args);
+			let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+			let body = args.collapsible_tokens(
+				struct_name,
+				quote! {
+					#request_focus
+					let #egui::InnerResponse { inner, response } = ui.with_layout(#layout, |ui| {
+						let mut widgets: Option<#egui::Response> = None;
+						#touch
+						#description
+						#rows
+						widgets
+					});
+					match inner {
+						Some(widgets) => response | widgets,
+						None => response,
+					}
+				},
+			);
 			let expanded = quote! {
-					impl #struct_name {
-							pub fn ui(&mut self, ui: &mut ::bevy_egui::egui::Ui) -> ::bevy_egui::egui::Response {
-								ui.with_layout(::bevy_egui::egui::Layout::top_down(::bevy_egui::egui::Align::Min), |ui| {
-											#(
-													{
-														ui.horizontal_wrapped(|ui| {
-															ui.add(#field_widgets);
-															ui.label(#field_docs);
-														});
-													}
-											)*
-								})
-									.response
+					impl #impl_generics #struct_name #ty_generics #where_clause {
+							pub fn #method(&mut self, ui: &mut #egui::Ui) -> #egui::Response {
+								#body
+							}
+
+							pub fn show(&mut self, ui: &mut #egui::Ui) {
+								self.#method(ui);
+							}
+
+							#[doc = #framed_doc]
+							pub fn ui_framed(&mut self, ui: &mut #egui::Ui, frame: #egui::Frame) -> #egui::Response {
+								let framed = frame.show(ui, |ui| self.#method(ui));
+								framed.response | framed.inner
+							}
+
+							#control_rect
+
+							#clicked_action
+
+							#summary
+
+							#reset_field
+
+							#validation_errors
+
+							#track_dirty
+
+							#[must_use = "the panel is shown either way, use `show` to only show it"]
+							pub fn ui_changed(&mut self, ui: &mut #egui::Ui) -> bool {
+								self.#method(ui).changed()
 							}
+
+							#inspector
+
+							#bevy_system
 					}
+
+					#builder
 			};
-			expanded.into()
+			Ok(expanded)
 		}
-		Data::Enum(DataEnum { .. }) => {
+		Data::Enum(DataEnum { variants, .. }) if variants.is_empty() => Err(syn::Error::new_spanned(
+			&input,
+			"ControlPanel cannot be derived for an enum with no variants, there is nothing to select",
+		)),
+		Data::Enum(DataEnum { variants, .. }) => {
 			let enum_name = &input.ident;
+			let strum = &args.strum_crate;
+			let unit_only = variants
+				.iter()
+				.all(|variant| matches!(variant.fields, Fields::Unit));
+			let (_, ty_generics, _) = input.generics.split_for_impl();
+			let mut generics = input.generics.clone();
+			if !unit_only {
+				// Variants carrying data are constructed through strum, so require it on
+				// the impl instead of letting the error surface inside the generated body.
+				generics
+					.make_where_clause()
+					.predicates
+					.push(parse_quote!(#enum_name #ty_generics: #strum::IntoEnumIterator));
+			}
+			let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+			// Every variant gets its own selectable label, and the variant is only
+			// constructed when its label is clicked rather than every frame. Skipped
+			// variants only get theirs while selected, so the value is still shown.
+			let variant_args = variants
+				.iter()
+				.map(VariantArgs::from_variant)
+				.collect::<syn::Result<Vec<_>>>()?;
+			let selectors = variants.iter().zip(&variant_args).map(|(variant, options)| {
+				let pattern = variant_pattern(variant);
+				let label = &options.label;
+				if options.skip {
+					let fold = fold_response(quote!(ui.selectable_label(true, #label)));
+					return quote! {
+						if matches!(self, #pattern) {
+							#fold
+						}
+					};
+				}
+				let construct = match &variant.fields {
+					Fields::Unit => quote!(Some(#pattern)),
+					_ => quote! {
+						<Self as #strum::IntoEnumIterator>::iter()
+							.find(|variant| matches!(variant, #pattern))
+					},
+				};
+				fold_response(quote! {
+					{
+						let selected = matches!(self, #pattern);
+						let mut response = ui.selectable_label(selected, #label);
+						if response.clicked() && !selected {
+							if let Some(variant) = #construct {
+								*self = variant;
+								response.mark_changed();
+							}
+						}
+						response
+					}
+				})
+			});
+			// The fields of the selected variant are edited beneath the selector.
+			let mut variant_arms = vec![];
+			let mut ids = vec![];
+			let mut has_variant_controls = false;
+			for variant in variants {
+				let ident = &variant.ident;
+				let binding = |member: &Member| match member {
+					Member::Named(ident) => format_ident!("field_{}", ident, span = Span::mixed_site()),
+					Member::Unnamed(index) => {
+						format_ident!("field_{}", index.index, span = Span::mixed_site())
+					}
+				};
+				// The value of a newtype variant is edited with its inferred widget even
+				// without `#[control]`, it is the only thing the variant holds.
+				let mut fields = variant.fields.clone();
+				if let Fields::Unnamed(unnamed) = &mut fields {
+					if let (1, Some(field)) = (unnamed.unnamed.len(), unnamed.unnamed.first_mut()) {
+						if !field.attrs.iter().any(|attr| attr.path().is_ident("control")) {
+							field.attrs.push(parse_quote!(#[control]));
+						}
+					}
+				}
+				let mut controls = parse_widgets_from_fields(&fields, &args, |member| {
+					let binding = binding(member);
+					quote!((*#binding))
+				})?;
+				for control in &mut controls {
+					control.name = format!("{ident}.{}", control.name);
+					ids.push((control.name.clone(), control.id_tokens(egui)));
+				}
+				if let Some(method) = controls.iter().find_map(|control| control.on_change.as_ref()) {
+					return Err(syn::Error::new_spanned(
+						method,
+						"on_change is not supported on the fields of enum variants",
+					));
+				}
+				if controls.is_empty() {
+					let pattern = variant_pattern(variant);
+					variant_arms.push(quote!(#pattern => {}));
+					continue;
+				}
+				has_variant_controls = true;
+				let pattern = match &variant.fields {
+					Fields::Named(_) => {
+						let members = controls.iter().map(|control| &control.member);
+						let bindings = controls.iter().map(|control| binding(&control.member));
+						quote!(Self::#ident { #(#members: #bindings,)* .. })
+					}
+					_ => {
+						let mut bindings = vec![quote!(_); variant.fields.len()];
+						for control in &controls {
+							if let Member::Unnamed(index) = &control.member {
+								let binding = binding(&control.member);
+								bindings[index.index as usize] = quote!(#binding);
+							}
+						}
+						quote!(Self::#ident(#(#bindings),*))
+					}
+				};
+				let rows = rows_tokens(&controls, &args);
+				let group = fold_response(quote!(response));
+				variant_arms.push(quote! {
+					#pattern => {
+						let group = ui.group(|ui| {
+							let mut widgets: Option<#egui::Response> = None;
+							#rows
+							widgets
+						});
+						if let Some(response) = group.inner {
+							#group
+						}
+					}
+				});
+			}
+			let control_rect = control_rect_tokens(&ids, &args);
+			let variant_controls = has_variant_controls.then(|| {
+				quote! {
+					match self {
+						#(#variant_arms)*
+					}
+				}
+			});
+			let constants = unit_only.then(|| {
+				let idents = variants.iter().map(|variant| &variant.ident);
+				let labels = variant_args.iter().map(|options| &options.label);
+				quote! {
+					/// Every variant of the enum, in declaration order.
+					pub const VARIANTS: &'static [Self] = &[#(Self::#idents),*];
+					/// The label of every variant in `VARIANTS`.
+					pub const VARIANT_LABELS: &'static [&'static str] = &[#(#labels),*];
+				}
+			});
+			let body = args.collapsible_tokens(
+				enum_name,
+				quote! {
+					#request_focus
+					let #egui::InnerResponse { inner, response } = ui.with_layout(#layout, |ui| {
+						let mut widgets: Option<#egui::Response> = None;
+						#touch
+						#description
+						#({ #selectors })*
+						#variant_controls
+						widgets
+					});
+					match inner {
+						Some(widgets) => response | widgets,
+						None => response,
+					}
+				},
+			);
 
 			let expanded = quote! {
-				impl #enum_name {
-					pub fn ui(&mut self, ui: &mut ::bevy_egui::egui::Ui) -> ::bevy_egui::egui::Response {
-						ui.with_layout(
-							::bevy_egui::egui::Layout::top_down(::bevy_egui::egui::Align::Min),
-							|ui| {
-								for variant in <#enum_name as ::strum::IntoEnumIterator>::iter() {
-									ui.selectable_value(self, variant, format!("{}", variant));
-								}
-							},
-						).response
+				impl #impl_generics #enum_name #ty_generics #where_clause {
+					#constants
+
+					pub fn #method(&mut self, ui: &mut #egui::Ui) -> #egui::Response {
+						#body
 					}
+
+					pub fn show(&mut self, ui: &mut #egui::Ui) {
+						self.#method(ui);
+					}
+
+					#[doc = #framed_doc]
+					pub fn ui_framed(&mut self, ui: &mut #egui::Ui, frame: #egui::Frame) -> #egui::Response {
+						let framed = frame.show(ui, |ui| self.#method(ui));
+						framed.response | framed.inner
+					}
+
+					#control_rect
+
+					#track_dirty
+
+					#[must_use = "the panel is shown either way, use `show` to only show it"]
+					pub fn ui_changed(&mut self, ui: &mut #egui::Ui) -> bool {
+						self.#method(ui).changed()
+					}
+
+					#inspector
+
+					#bevy_system
 				}
 			};
 
-			expanded.into()
+			Ok(expanded)
 		}
-		_ => panic!("expected a struct or enum"),
+		Data::Union(_) => Err(syn::Error::new_spanned(
+			&input,
+			"ControlPanel cannot be derived for unions, only for structs and enums",
+		)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Parse a single attribute from source, so the spans of errors have source text.
+	fn attr(source: &str) -> Attribute {
+		syn::parse::Parser::parse_str(Attribute::parse_outer, source)
+			.expect("valid attribute")
+			.remove(0)
+	}
+
+	/// The message of an error along with the source text it points at.
+	fn error<T>(result: syn::Result<T>) -> (String, String) {
+		let Err(error) = result else {
+			panic!("expected an error");
+		};
+		let text = error.span().source_text().unwrap_or_default();
+		(error.to_string(), text)
+	}
+
+	#[test]
+	fn slider_range_and_options() {
+		let args = ControlArgs::from_attr(&attr(
+			r#"#[control(slider(0.0..=self.max, clamp, text = "speed"))]"#,
+		))
+		.unwrap();
+		let Some(WidgetKind::Slider(slider)) = args.widget else {
+			panic!("expected a slider");
+		};
+		assert_eq!(slider.range, Some(parse_quote!(0.0..=self.max)));
+		assert!(slider.clamp);
+		assert!(!slider.fill);
+		assert_eq!(
+			slider.text.map(|text| text.value()).as_deref(),
+			Some("speed")
+		);
+	}
+
+	#[test]
+	fn bare_slider_has_no_range() {
+		let args = ControlArgs::from_attr(&parse_quote!(#[control(slider)])).unwrap();
+		let Some(WidgetKind::Slider(slider)) = args.widget else {
+			panic!("expected a slider");
+		};
+		assert!(slider.range.is_none());
+		assert!(slider.text.is_none());
+	}
+
+	#[test]
+	fn bare_control_has_no_widget() {
+		let args = ControlArgs::from_attr(&parse_quote!(#[control])).unwrap();
+		assert!(args.widget.is_none());
+		assert!(args.skip.is_none());
+	}
+
+	#[test]
+	fn unknown_slider_option() {
+		assert_eq!(
+			error(ControlArgs::from_attr(&attr(
+				"#[control(slider(0..=10, log))]"
+			))),
+			("unsupported slider option".to_string(), "log".to_string())
+		);
+	}
+
+	#[test]
+	fn unknown_control() {
+		let (message, text) = error(ControlArgs::from_attr(&attr("#[control(knob)]")));
+		assert_eq!(message, "unsupported control");
+		assert_eq!(text, "knob");
+	}
+
+	#[test]
+	fn two_widgets_conflict() {
+		let (message, text) = error(ControlArgs::from_attr(&attr(
+			"#[control(slider(0..=10), textbox)]",
+		)));
+		assert_eq!(message, "a control renders a single widget");
+		assert_eq!(text, "textbox");
+	}
+
+	#[test]
+	fn skip_conflicts_with_a_widget() {
+		let (message, text) = error(ControlArgs::from_attr(&attr(
+			"#[control(skip, slider(0..=10))]",
+		)));
+		assert_eq!(
+			message,
+			"`skip` cannot be combined with a widget kind, the field renders nothing"
+		);
+		assert_eq!(text, "skip");
+	}
+
+	#[test]
+	fn debounce_needs_on_change() {
+		let (message, text) = error(ControlArgs::from_attr(&attr("#[control(debounce = 0.5)]")));
+		assert_eq!(message, "`debounce` delays `on_change`, which is missing");
+		assert_eq!(text, "0.5");
+	}
+
+	#[test]
+	fn container_options() {
+		let args = ContainerArgs::from_attrs(&[
+			attr(r#"#[controls(layout = "left_to_right", grid)]"#),
+			attr(r#"#[controls(method = "panel", missing_doc = "?")]"#),
+		])
+		.unwrap();
+		assert_eq!(args.layout, "left_to_right");
+		assert!(args.grid);
+		assert_eq!(args.method, "panel");
+		assert_eq!(args.missing_doc.as_deref(), Some("?"));
+		assert!(!args.control_rects);
+	}
+
+	#[test]
+	fn container_defaults_ignore_other_attributes() {
+		let args = ContainerArgs::from_attrs(&[attr("#[derive(Debug)]")]).unwrap();
+		assert_eq!(args.layout, "top_down");
+		assert_eq!(args.align, "Min");
+		assert_eq!(args.method, "ui");
+		assert!(!args.grid);
+	}
+
+	#[test]
+	fn unknown_container_option() {
+		let (message, text) = error(ContainerArgs::from_attrs(&[attr(
+			"#[controls(grid, columns = 3)]",
+		)]));
+		assert_eq!(message, "unsupported controls option");
+		assert_eq!(text, "columns");
+	}
+
+	#[test]
+	fn unknown_layout() {
+		let (message, text) = error(ContainerArgs::from_attrs(&[attr(
+			r#"#[controls(layout = "diagonal")]"#,
+		)]));
+		assert_eq!(
+			message,
+			"expected one of \"top_down\", \"left_to_right\" or \"right_to_left\""
+		);
+		assert_eq!(text, "\"diagonal\"");
+	}
+
+	#[test]
+	fn align_widgets_needs_grid() {
+		let (message, text) = error(ContainerArgs::from_attrs(&[attr(
+			r#"#[controls(align_widgets = "right")]"#,
+		)]));
+		assert_eq!(
+			message,
+			"`align_widgets` aligns the widgets within their grid column, add `grid`"
+		);
+		assert_eq!(text, "\"right\"");
+	}
+
+	#[test]
+	fn expand_simple_struct() {
+		let tokens = expand(parse_quote! {
+			struct Settings {
+				/// Whether the feature is on.
+				#[control]
+				enabled: bool,
+				#[control(slider(0..=10))]
+				level: u8,
+			}
+		})
+		.unwrap()
+		.to_string();
+		assert!(tokens.contains("impl Settings"), "{tokens}");
+		assert!(tokens.contains("pub fn ui"), "{tokens}");
+		assert!(tokens.contains("Checkbox"), "{tokens}");
+		assert!(tokens.contains("Slider :: new"), "{tokens}");
+		assert!(
+			tokens.contains("\"Whether the feature is on.\""),
+			"{tokens}"
+		);
+		assert!(tokens.contains("& mut self . enabled"), "{tokens}");
+		assert!(!tokens.contains("control_rect"), "{tokens}");
+	}
+
+	#[test]
+	fn expand_reports_field_errors() {
+		let (message, text) = error(expand(
+			syn::parse_str("struct Settings { #[control(knob)] level: u8 }").unwrap(),
+		));
+		assert_eq!(message, "unsupported control");
+		assert_eq!(text, "knob");
 	}
 }
@@ -0,0 +1,40 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Reactor {
+	#[control(slider(0.0..=1.0, fill), accent = "0xFF8800")]
+	pub temperature: f32,
+	#[control(slider(0.0..=1.0, fill))]
+	pub pressure: f32,
+}
+
+/// The rectangles filled with `color` painted in `output`.
+fn filled_with(output: &egui::FullOutput, color: egui::Color32) -> Vec<egui::Rect> {
+	output
+		.shapes
+		.iter()
+		.filter_map(|egui::epaint::ClippedShape(_, shape)| match shape {
+			egui::Shape::Rect(rect) if rect.fill == color => Some(rect.rect),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn only_the_accented_slider_is_filled_with_the_accent() {
+	let mut reactor = Reactor {
+		temperature: 0.5,
+		pressure: 0.5,
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| reactor.show(ui));
+	let accent = egui::Color32::from_rgb(0xFF, 0x88, 0x00);
+	let accented = filled_with(&output, accent);
+	let selection = egui::Visuals::default().selection.bg_fill;
+
+	assert_eq!(accented.len(), 1, "{accented:?}");
+	assert!(accented[0].top() < 30.0, "{accented:?}");
+	assert_eq!(filled_with(&output, selection).len(), 1);
+}
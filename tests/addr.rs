@@ -0,0 +1,60 @@
+mod common;
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Server {
+	#[control]
+	pub host: IpAddr,
+	#[control(addr)]
+	pub bind: SocketAddr,
+}
+
+impl Default for Server {
+	fn default() -> Self {
+		Self {
+			host: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+			bind: "0.0.0.0:8080".parse().unwrap(),
+		}
+	}
+}
+
+/// The frames focusing the text edit ending at `end` and typing `text` at its end.
+fn typing(end: egui::Pos2, text: &str) -> Vec<egui::RawInput> {
+	let mut inputs = common::click(end + egui::vec2(20.0, 0.0));
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text(text.to_string())],
+		..Default::default()
+	});
+	inputs.push(Default::default());
+	inputs
+}
+
+#[test]
+fn valid_addresses_are_written_back() {
+	let mut server = Server::default();
+	let output = common::run_frames(vec![Default::default()], |ui| server.show(ui));
+	let host = common::text_rect(&output, "10.0.0.1").right_center();
+	let mut changed = false;
+	common::run_frames(typing(host, "2"), |ui| changed |= server.ui(ui).changed());
+
+	assert_eq!(server.host, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 12)));
+	assert!(changed);
+}
+
+#[test]
+fn invalid_addresses_are_kept_as_text_only() {
+	let mut server = Server::default();
+	let output = common::run_frames(vec![Default::default()], |ui| server.show(ui));
+	let bind = common::text_rect(&output, "0.0.0.0:8080").right_center();
+	let mut changed = false;
+	let output = common::run_frames(typing(bind, "x"), |ui| changed |= server.ui(ui).changed());
+
+	assert_eq!(server.bind, "0.0.0.0:8080".parse().unwrap());
+	assert!(!changed);
+	let text = common::rendered_text(&output);
+	assert!(text.contains(&"0.0.0.0:8080x".to_string()), "{text:?}");
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Renderer {
+	/// Tweak the shadow bias.
+	#[control(slider(0.0..=1.0), advanced)]
+	pub shadow_bias: f32,
+	/// Whether shadows are cast.
+	#[control(bool)]
+	pub shadows: bool,
+	/// Tweak the cascade count.
+	#[control(drag, advanced)]
+	pub cascades: u8,
+}
+
+#[test]
+fn advanced_fields_start_collapsed_after_the_others() {
+	let mut renderer = Renderer::default();
+	let output = common::run_frames(vec![Default::default()], |ui| renderer.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert_eq!(text, vec!["Whether shadows are cast.", "Advanced"]);
+}
+
+#[test]
+fn opening_the_header_shows_them() {
+	let mut renderer = Renderer::default();
+	let output = common::run_frames(vec![Default::default()], |ui| renderer.show(ui));
+	let header = common::text_center(&output, "Advanced");
+	let mut inputs = common::click(header);
+	// Let the header animate open.
+	inputs.extend(vec![Default::default(); 30]);
+	let output = common::run_frames(inputs, |ui| renderer.show(ui));
+	let text = common::rendered_text(&output);
+
+	let advanced = common::text_rect(&output, "Advanced");
+	let bias = common::text_rect(&output, "Tweak the shadow bias.");
+	let cascades = common::text_rect(&output, "Tweak the cascade count.");
+	assert!(bias.top() > advanced.bottom(), "{text:?}");
+	assert!(cascades.top() > bias.bottom(), "{text:?}");
+}
+
+thread_local! {
+	/// How many times `counted` rendered on this thread.
+	static RENDERS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+fn counted(value: &mut u8, ui: &mut egui::Ui) -> egui::Response {
+	RENDERS.with(|renders| renders.set(renders.get() + 1));
+	ui.add(egui::DragValue::new(value))
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Expensive {
+	/// Whether shadows are cast.
+	#[control(bool)]
+	pub shadows: bool,
+	/// Something costly to render.
+	#[control(with = "counted", advanced)]
+	pub costly: u8,
+}
+
+#[test]
+fn collapsed_fields_are_never_rendered() {
+	let mut expensive = Expensive::default();
+	let output = common::run_frames(vec![Default::default(); 3], |ui| expensive.show(ui));
+	assert_eq!(RENDERS.with(|renders| renders.get()), 0);
+
+	let mut inputs = common::click(common::text_center(&output, "Advanced"));
+	inputs.push(Default::default());
+	common::run_frames(inputs, |ui| expensive.show(ui));
+	assert!(RENDERS.with(|renders| renders.get()) > 0);
+}
@@ -0,0 +1,104 @@
+//! The generated labels are string literals, so a panel allocates no more per frame than the
+//! same egui calls written by hand.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+/// Counts the allocations of the current thread, so tests running in parallel do not mix.
+struct Counting;
+
+thread_local! {
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// The allocations of the last of a few frames showing `panel`, once egui's caches are warm.
+fn allocations_per_frame(mut panel: impl FnMut(&mut egui::Ui)) -> usize {
+	let ctx = egui::Context::default();
+	let mut frame = || {
+		let _ = ctx.run(Default::default(), |ctx| {
+			egui::CentralPanel::default().show(ctx, |ui| panel(ui));
+		});
+	};
+	for _ in 0..3 {
+		frame();
+	}
+	let before = ALLOCATIONS.with(Cell::get);
+	frame();
+	ALLOCATIONS.with(Cell::get) - before
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ControlPanel)]
+pub enum Quality {
+	Low,
+	Medium,
+	High,
+	Ultra,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Graphics {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+#[test]
+fn enum_labels_are_not_formatted_every_frame() {
+	let mut quality = Quality::High;
+	let derived = allocations_per_frame(|ui| quality.show(ui));
+
+	let mut quality = Quality::High;
+	let by_hand = allocations_per_frame(|ui| {
+		ui.with_layout(egui::Layout::top_down(egui::Align::Min), |ui| {
+			for (variant, label) in Quality::VARIANTS.iter().zip(Quality::VARIANT_LABELS) {
+				if ui.selectable_label(quality == *variant, *label).clicked() {
+					quality = *variant;
+				}
+			}
+		});
+	});
+
+	assert!(derived <= by_hand, "{derived} > {by_hand}");
+}
+
+#[test]
+fn doc_labels_are_not_formatted_every_frame() {
+	let mut graphics = Graphics::default();
+	let derived = allocations_per_frame(|ui| graphics.show(ui));
+
+	let mut graphics = Graphics::default();
+	let by_hand = allocations_per_frame(|ui| {
+		ui.with_layout(egui::Layout::top_down(egui::Align::Min), |ui| {
+			ui.horizontal_wrapped(|ui| {
+				ui.checkbox(&mut graphics.vsync, "");
+				ui.label("Whether vsync is on.");
+			});
+			ui.horizontal_wrapped(|ui| {
+				ui.checkbox(&mut graphics.shadows, "");
+				ui.label("Whether shadows are drawn.");
+			});
+		});
+	});
+
+	assert!(derived <= by_hand, "{derived} > {by_hand}");
+}
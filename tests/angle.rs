@@ -0,0 +1,55 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Heading {
+	#[control(angle_deg(wrap))]
+	pub yaw: f32,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Pitch {
+	#[control(angle_deg)]
+	pub pitch: f32,
+}
+
+/// The frames pressing on the slider in the top left corner and dragging it past its right end.
+fn dragging_past_the_end() -> Vec<egui::RawInput> {
+	let start = egui::pos2(100.0, 15.0);
+	let mut inputs = vec![
+		common::pointer(start, None),
+		common::pointer(start, Some(true)),
+	];
+	for step in 1..=5 {
+		inputs.push(common::pointer(
+			start + egui::vec2(40.0 * step as f32, 0.0),
+			None,
+		));
+	}
+	inputs
+}
+
+#[test]
+fn dragging_past_the_end_wraps_around() {
+	let mut heading = Heading { yaw: 350.0 };
+	common::run_frames(dragging_past_the_end(), |ui| heading.show(ui));
+	assert_eq!(heading.yaw, 0.0);
+
+	let mut pitch = Pitch { pitch: 350.0 };
+	common::run_frames(dragging_past_the_end(), |ui| pitch.show(ui));
+	assert_eq!(pitch.pitch, 360.0);
+}
+
+#[test]
+fn out_of_range_values_are_wrapped() {
+	let mut heading = Heading { yaw: -90.0 };
+	let output = common::run_frames(vec![Default::default()], |ui| heading.show(ui));
+	assert_eq!(heading.yaw, 270.0);
+	assert!(common::rendered_text(&output).contains(&"270°".to_string()));
+
+	heading.yaw = 725.0;
+	common::run_frames(vec![Default::default()], |ui| heading.show(ui));
+	assert_eq!(heading.yaw, 5.0);
+}
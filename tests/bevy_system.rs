@@ -0,0 +1,44 @@
+#![cfg(feature = "bevy")]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::{egui, EguiContext, EguiPlugin};
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, Resource, ControlPanel)]
+pub struct Settings {
+	/// How loud it is.
+	#[control(slider(0.0..=1.0))]
+	pub volume: f32,
+}
+
+#[test]
+fn the_system_shows_the_resource_in_a_window() {
+	let mut app = App::new();
+	// The bits of the render plugins `EguiPlugin` expects, without a renderer.
+	app.add_plugins((
+		MinimalPlugins,
+		AssetPlugin::default(),
+		InputPlugin,
+		WindowPlugin {
+			primary_window: None,
+			..default()
+		},
+	))
+	.add_asset::<Shader>()
+	.add_asset::<Image>()
+	.add_plugins(EguiPlugin)
+	.init_resource::<Settings>()
+	.add_systems(Update, Settings::window_system("Settings"));
+	let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+	app.update();
+	app.update();
+
+	let mut context = app.world.get_mut::<EguiContext>(window).unwrap();
+	let ctx = context.get_mut();
+	let window = ctx.used_rect();
+	assert!(window.area() > 0.0, "{window:?}");
+	let layer = egui::LayerId::new(egui::Order::Middle, egui::Id::new("Settings"));
+	assert_eq!(ctx.layer_id_at(window.center()), Some(layer));
+}
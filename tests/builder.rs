@@ -0,0 +1,88 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Vehicle {
+	/// How fast the vehicle drives.
+	#[control(slider(0.0..=10.0))]
+	pub speed: f32,
+	/// Whether the headlights are on.
+	#[control(bool)]
+	pub headlights: bool,
+	/// The name painted on the side.
+	#[control(textbox)]
+	pub name: String,
+}
+
+/// The top of the text shape painted with exactly `text`.
+fn top(output: &bevy_egui::egui::FullOutput, text: &str) -> f32 {
+	common::text_rect(output, text).top()
+}
+
+#[test]
+fn custom_rows_go_between_fields() {
+	let mut vehicle = Vehicle::default();
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		vehicle
+			.ui_builder(ui)
+			.field("headlights")
+			.custom(|ui| {
+				ui.label("Custom row");
+			})
+			.field("speed")
+			.rest();
+	});
+
+	let headlights = top(&output, "Whether the headlights are on.");
+	let custom = top(&output, "Custom row");
+	let speed = top(&output, "How fast the vehicle drives.");
+	let name = top(&output, "The name painted on the side.");
+	assert!(headlights < custom, "{headlights} {custom}");
+	assert!(custom < speed, "{custom} {speed}");
+	assert!(speed < name, "{speed} {name}");
+}
+
+#[test]
+fn rest_renders_every_field_once() {
+	let mut vehicle = Vehicle::default();
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		vehicle.ui_builder(ui).field("name").rest();
+	});
+	let text = common::rendered_text(&output);
+	for doc in [
+		"How fast the vehicle drives.",
+		"Whether the headlights are on.",
+		"The name painted on the side.",
+	] {
+		assert_eq!(
+			text.iter().filter(|text| *text == doc).count(),
+			1,
+			"{text:?}"
+		);
+	}
+}
+
+#[test]
+fn edits_go_through_the_builder() {
+	let mut vehicle = Vehicle::default();
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		vehicle.ui_builder(ui).rest();
+	});
+	let checkbox = common::left_of(&output, "Whether the headlights are on.");
+	let mut changed = false;
+	common::run_frames(common::click(checkbox), |ui| {
+		changed |= vehicle.ui_builder(ui).rest().changed();
+	});
+	assert!(vehicle.headlights);
+	assert!(changed);
+}
+
+#[test]
+#[should_panic(expected = "Vehicle has no control named \"wheels\"")]
+fn unknown_fields_panic() {
+	let mut vehicle = Vehicle::default();
+	common::run_frames(vec![Default::default()], |ui| {
+		vehicle.ui_builder(ui).field("wheels");
+	});
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Level {
+	/// Respawn the player.
+	#[control(button)]
+	pub respawn: bool,
+	#[control(button)]
+	pub reset_level: bool,
+	#[control]
+	pub paused: bool,
+}
+
+#[test]
+fn clicked_action_names_the_clicked_button() {
+	let mut level = Level::default();
+	let output = common::run_frames(vec![Default::default()], |ui| level.show(ui));
+	let respawn = common::text_center(&output, "Respawn the player.");
+	let reset = common::text_center(&output, "Reset level");
+
+	let mut actions = vec![];
+	common::run_frames(common::click(reset), |ui| {
+		actions.push(level.clicked_action(ui))
+	});
+	assert_eq!(actions, [None, None, Some("reset_level")]);
+
+	let mut actions = vec![];
+	common::run_frames(common::click(respawn), |ui| {
+		actions.push(level.clicked_action(ui))
+	});
+	assert_eq!(actions, [None, None, Some("respawn")]);
+}
+
+#[test]
+fn buttons_are_only_set_on_the_frame_they_are_clicked() {
+	let mut level = Level::default();
+	let output = common::run_frames(vec![Default::default()], |ui| level.show(ui));
+	let respawn = common::text_center(&output, "Respawn the player.");
+
+	let mut clicked = vec![];
+	let mut inputs = common::click(respawn);
+	inputs.push(Default::default());
+	common::run_frames(inputs, |ui| {
+		clicked.push(level.ui(ui).clicked());
+		assert_eq!(level.respawn, clicked[clicked.len() - 1]);
+	});
+	assert_eq!(clicked, [false, false, true, false]);
+}
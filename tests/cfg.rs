@@ -0,0 +1,40 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+/// The `inspector` feature stands in for any feature gating debug-only fields, the tests run
+/// both with and without it.
+#[derive(Debug, Default, ControlPanel)]
+#[controls(summary, control_rects)]
+pub struct Tools {
+	/// Whether the game is paused.
+	#[control(bool)]
+	pub paused: bool,
+	/// Whether colliders are drawn.
+	#[cfg(feature = "inspector")]
+	#[control(bool)]
+	pub show_colliders: bool,
+}
+
+#[test]
+fn gated_fields_are_left_out_everywhere() {
+	let enabled = cfg!(feature = "inspector");
+	let mut tools = Tools::default();
+	let mut colliders = None;
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		tools.show(ui);
+		colliders = Tools::control_rect(ui.ctx(), "show_colliders");
+	});
+	let text = common::rendered_text(&output);
+	let rows = [
+		"Whether the game is paused.",
+		"Whether colliders are drawn.",
+	]
+	.iter()
+	.filter(|doc| text.contains(&doc.to_string()))
+	.count();
+
+	assert_eq!(rows, 1 + enabled as usize, "{text:?}");
+	assert_eq!(colliders.is_some(), enabled);
+	assert_eq!(tools.summary().split(", ").count(), 1 + enabled as usize);
+}
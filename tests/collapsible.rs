@@ -0,0 +1,86 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(collapsible)]
+pub struct GraphicsSettings {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(collapsible = "Audio")]
+pub struct AudioSettings {
+	/// Whether sound is muted.
+	#[control(bool)]
+	pub muted: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Settings {
+	#[control]
+	pub graphics: GraphicsSettings,
+	#[control]
+	pub audio: AudioSettings,
+}
+
+#[test]
+fn panels_start_collapsed_under_their_title() {
+	let mut settings = Settings::default();
+	let output = common::run_frames(vec![Default::default()], |ui| settings.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert_eq!(text, ["GraphicsSettings", "Audio"]);
+}
+
+#[test]
+fn clicking_the_header_shows_the_panel() {
+	let mut settings = Settings::default();
+	let output = common::run_frames(vec![Default::default()], |ui| settings.show(ui));
+	let mut inputs = common::click(common::text_center(&output, "Audio"));
+	inputs.push(Default::default());
+	let output = common::run_frames(inputs, |ui| settings.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(
+		text.contains(&"Whether sound is muted.".to_string()),
+		"{text:?}"
+	);
+	assert!(
+		!text.contains(&"Whether vsync is on.".to_string()),
+		"{text:?}"
+	);
+}
+
+thread_local! {
+	/// How many times `counted` rendered on this thread.
+	static RENDERS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+fn counted(value: &mut u8, ui: &mut egui::Ui) -> egui::Response {
+	RENDERS.with(|renders| renders.set(renders.get() + 1));
+	ui.add(egui::DragValue::new(value))
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(collapsible = "Expensive")]
+pub struct Expensive {
+	/// Something costly to render.
+	#[control(with = "counted")]
+	pub costly: u8,
+}
+
+#[test]
+fn collapsed_panels_never_render_their_rows() {
+	let mut expensive = Expensive::default();
+	let output = common::run_frames(vec![Default::default(); 3], |ui| expensive.show(ui));
+	assert_eq!(RENDERS.with(|renders| renders.get()), 0);
+
+	let mut inputs = common::click(common::text_center(&output, "Expensive"));
+	inputs.push(Default::default());
+	common::run_frames(inputs, |ui| expensive.show(ui));
+	assert!(RENDERS.with(|renders| renders.get()) > 0);
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::Color;
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Clone, ControlPanel)]
+pub struct Palette {
+	/// An sRGB color.
+	#[control(color)]
+	pub srgb: Color,
+	/// A color stored in linear space.
+	#[control(color)]
+	pub linear: Color,
+	/// A color stored as HSL.
+	#[control(color)]
+	pub hsla: bevy::render::color::Color,
+	/// A widget added through `ui.add`, next to the color buttons.
+	#[control(slider(0.0..=1.0))]
+	pub intensity: f32,
+}
+
+fn assert_close(left: [f32; 4], right: [f32; 4]) {
+	for (l, r) in left.iter().zip(right.iter()) {
+		assert!((l - r).abs() < 1e-6, "{left:?} != {right:?}");
+	}
+}
+
+#[test]
+fn unchanged_edit_round_trips() {
+	let original = Palette {
+		srgb: Color::rgba(0.1, 0.2, 0.3, 0.4),
+		linear: Color::rgba_linear(0.01, 0.5, 0.9, 0.25),
+		hsla: Color::hsla(200.0, 0.5, 0.5, 1.0),
+		intensity: 0.5,
+	};
+	let mut palette = original.clone();
+
+	let ctx = egui::Context::default();
+	for _ in 0..2 {
+		let _ = ctx.run(egui::RawInput::default(), |ctx| {
+			egui::CentralPanel::default().show(ctx, |ui| {
+				palette.ui(ui);
+			});
+		});
+	}
+
+	assert_close(palette.srgb.as_rgba_f32(), original.srgb.as_rgba_f32());
+	assert_close(
+		palette.linear.as_linear_rgba_f32(),
+		original.linear.as_linear_rgba_f32(),
+	);
+	assert_close(palette.hsla.as_hsla_f32(), original.hsla.as_hsla_f32());
+	assert_eq!(palette.intensity, original.intensity);
+}
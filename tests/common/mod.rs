@@ -0,0 +1,101 @@
+//! Helpers for driving generated panels in a headless `egui::Context`.
+#![allow(dead_code)]
+
+use bevy_egui::egui;
+
+/// Render `panel` inside a `CentralPanel` for one frame per input, in order,
+/// returning the output of the last frame.
+pub fn run_frames(
+	inputs: Vec<egui::RawInput>,
+	mut panel: impl FnMut(&mut egui::Ui),
+) -> egui::FullOutput {
+	let ctx = egui::Context::default();
+	let mut output = None;
+	for input in inputs {
+		output = Some(ctx.run(input, |ctx| {
+			egui::CentralPanel::default().show(ctx, |ui| panel(ui));
+		}));
+	}
+	output.expect("at least one frame to run")
+}
+
+/// The text and bounding box of every text shape painted in `output`.
+pub fn text_shapes(output: &egui::FullOutput) -> Vec<(String, egui::Rect)> {
+	output
+		.shapes
+		.iter()
+		.filter_map(|egui::epaint::ClippedShape(_, shape)| match shape {
+			egui::Shape::Text(text) => {
+				Some((text.galley.text().to_string(), shape.visual_bounding_rect()))
+			}
+			_ => None,
+		})
+		.collect()
+}
+
+/// The text of every text shape painted in `output`.
+pub fn rendered_text(output: &egui::FullOutput) -> Vec<String> {
+	text_shapes(output)
+		.into_iter()
+		.map(|(text, _)| text)
+		.collect()
+}
+
+/// The bounding box of the text shape painted with exactly `text`.
+pub fn text_rect(output: &egui::FullOutput, text: &str) -> egui::Rect {
+	text_shapes(output)
+		.into_iter()
+		.find(|(shape_text, _)| shape_text == text)
+		.unwrap_or_else(|| panic!("no text shape {text:?} was painted"))
+		.1
+}
+
+/// The center of the text shape painted with exactly `text`.
+pub fn text_center(output: &egui::FullOutput, text: &str) -> egui::Pos2 {
+	text_rect(output, text).center()
+}
+
+/// Where the widget on the left of the label painted with exactly `text` is,
+/// for rows made of a small widget followed by its description.
+pub fn left_of(output: &egui::FullOutput, text: &str) -> egui::Pos2 {
+	text_rect(output, text).left_center() - egui::vec2(15.0, 0.0)
+}
+
+/// A frame where the pointer hovers `pos`, optionally pressing or releasing the primary button.
+pub fn pointer(pos: egui::Pos2, pressed: Option<bool>) -> egui::RawInput {
+	let mut events = vec![egui::Event::PointerMoved(pos)];
+	if let Some(pressed) = pressed {
+		events.push(egui::Event::PointerButton {
+			pos,
+			button: egui::PointerButton::Primary,
+			pressed,
+			modifiers: Default::default(),
+		});
+	}
+	egui::RawInput {
+		events,
+		..Default::default()
+	}
+}
+
+/// The frames clicking at `pos`.
+pub fn click(pos: egui::Pos2) -> Vec<egui::RawInput> {
+	vec![
+		pointer(pos, None),
+		pointer(pos, Some(true)),
+		pointer(pos, Some(false)),
+	]
+}
+
+/// A frame where `key` is pressed.
+pub fn key(key: egui::Key) -> egui::RawInput {
+	egui::RawInput {
+		events: vec![egui::Event::Key {
+			key,
+			pressed: true,
+			repeat: false,
+			modifiers: Default::default(),
+		}],
+		..Default::default()
+	}
+}
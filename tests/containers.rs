@@ -0,0 +1,52 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Layers {
+	#[control]
+	pub opacities: Vec<Option<f32>>,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Blend {
+	#[control]
+	pub weights: Option<Vec<f32>>,
+}
+
+/// Where the checkbox right of the label painted with exactly `text` is.
+fn right_of(output: &egui::FullOutput, text: &str) -> egui::Pos2 {
+	common::text_rect(output, text).right_center() + egui::vec2(15.0, 0.0)
+}
+
+#[test]
+fn a_list_of_options_renders_an_editor_per_item() {
+	let mut layers = Layers {
+		opacities: vec![Some(0.5), None],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| layers.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(text.contains(&"0.5".to_string()), "{text:?}");
+
+	// Checking the second item fills in its default value.
+	let second = right_of(&output, "1");
+	common::run_frames(common::click(second), |ui| layers.show(ui));
+	assert_eq!(layers.opacities, [Some(0.5), Some(0.0)]);
+}
+
+#[test]
+fn an_optional_list_is_edited_once_checked() {
+	let mut blend = Blend::default();
+	let output = common::run_frames(vec![Default::default()], |ui| blend.show(ui));
+	assert!(!common::rendered_text(&output).contains(&"0".to_string()));
+
+	common::run_frames(common::click(egui::pos2(15.0, 15.0)), |ui| blend.show(ui));
+	assert_eq!(blend.weights, Some(vec![]));
+
+	blend.weights = Some(vec![0.5, 2.5]);
+	let output = common::run_frames(vec![Default::default()], |ui| blend.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(text.contains(&"0.5".to_string()), "{text:?}");
+	assert!(text.contains(&"2.5".to_string()), "{text:?}");
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+#[controls(control_rects)]
+pub struct Player {
+	#[control]
+	pub name: String,
+	#[control(slider(0.0..=10.0))]
+	pub move_speed: f32,
+	/// Whether the player can fly.
+	#[control(bool)]
+	pub flying: bool,
+	pub health: f32,
+}
+
+fn player() -> Player {
+	Player {
+		name: "ferris".to_string(),
+		move_speed: 5.0,
+		flying: false,
+		health: 1.0,
+	}
+}
+
+/// The rect of the widget of `field` once `player` was shown for a frame.
+fn shown_rect(player: &mut Player, field: &str) -> (egui::FullOutput, Option<egui::Rect>) {
+	let mut rect = None;
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		player.show(ui);
+		rect = Player::control_rect(ui.ctx(), field);
+	});
+	(output, rect)
+}
+
+#[test]
+fn rects_are_only_given_to_controls() {
+	let mut player = player();
+
+	assert!(shown_rect(&mut player, "move_speed").1.is_some());
+	assert_ne!(
+		shown_rect(&mut player, "name").1,
+		shown_rect(&mut player, "move_speed").1
+	);
+	assert_eq!(shown_rect(&mut player, "health").1, None);
+	assert_eq!(shown_rect(&mut player, "jump").1, None);
+}
+
+#[test]
+fn the_rect_is_where_the_widget_is() {
+	let mut player = player();
+	let (output, rect) = shown_rect(&mut player, "move_speed");
+	let rect = rect.expect("the rect to be recorded");
+
+	assert!(
+		rect.contains(common::text_center(&output, "5.0")),
+		"{rect:?}"
+	);
+	assert!(
+		!rect.contains(common::text_center(&output, "ferris")),
+		"{rect:?}"
+	);
+}
+
+#[test]
+fn clicking_the_rect_interacts_with_the_widget() {
+	let mut player = player();
+	let (_, rect) = shown_rect(&mut player, "flying");
+	let rect = rect.expect("the rect to be recorded");
+
+	common::run_frames(common::click(rect.center()), |ui| player.show(ui));
+	assert!(player.flying);
+}
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+#[controls(control_rects)]
+pub enum Movement {
+	Walk(f32),
+	Fly {
+		#[control]
+		altitude: f32,
+	},
+}
+
+#[test]
+fn variant_fields_are_named_after_their_variant() {
+	let mut movement = Movement::Fly { altitude: 2.0 };
+	let mut rects = vec![];
+	common::run_frames(vec![Default::default()], |ui| {
+		movement.show(ui);
+		rects = ["Fly.altitude", "altitude", "Walk.0"]
+			.map(|field| Movement::control_rect(ui.ctx(), field))
+			.to_vec();
+	});
+
+	// `Walk.0` is a control, but not shown while flying.
+	assert_eq!(rects[1..], [None, None]);
+	assert!(rects[0].is_some());
+}
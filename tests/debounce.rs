@@ -0,0 +1,76 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Terrain {
+	#[control(slider(0.0..=10.0), on_change = "rebuild", debounce = 0.2)]
+	pub roughness: f32,
+	pub rebuilt: usize,
+}
+
+impl Terrain {
+	fn rebuild(&mut self) {
+		self.rebuilt += 1;
+	}
+}
+
+/// The `pointer` frame at `time` seconds.
+fn at(time: f64, pos: egui::Pos2, pressed: Option<bool>) -> egui::RawInput {
+	egui::RawInput {
+		time: Some(time),
+		..common::pointer(pos, pressed)
+	}
+}
+
+/// Where the slider in the top left corner starts.
+const LEFT_END: egui::Pos2 = egui::pos2(9.0, 15.0);
+
+/// The value of `terrain.rebuilt` after every frame of `inputs`.
+fn rebuilt_per_frame(terrain: &mut Terrain, inputs: Vec<egui::RawInput>) -> Vec<usize> {
+	let mut rebuilt = vec![];
+	common::run_frames(inputs, |ui| {
+		terrain.show(ui);
+		rebuilt.push(terrain.rebuilt);
+	});
+	rebuilt
+}
+
+#[test]
+fn dragging_only_rebuilds_once_released() {
+	let mut terrain = Terrain::default();
+	let rebuilt = rebuilt_per_frame(
+		&mut terrain,
+		vec![
+			at(0.0, LEFT_END, None),
+			at(0.05, LEFT_END, Some(true)),
+			at(0.1, LEFT_END + egui::vec2(30.0, 0.0), None),
+			at(0.15, LEFT_END + egui::vec2(60.0, 0.0), None),
+			at(0.2, LEFT_END + egui::vec2(60.0, 0.0), Some(false)),
+			at(1.0, LEFT_END + egui::vec2(60.0, 0.0), None),
+		],
+	);
+
+	assert!(terrain.roughness > 0.0);
+	assert_eq!(rebuilt, [0, 0, 0, 0, 1, 1]);
+}
+
+#[test]
+fn holding_still_rebuilds_after_the_quiet_period() {
+	let mut terrain = Terrain::default();
+	let held = LEFT_END + egui::vec2(30.0, 0.0);
+	let rebuilt = rebuilt_per_frame(
+		&mut terrain,
+		vec![
+			at(0.0, LEFT_END, None),
+			at(0.05, LEFT_END, Some(true)),
+			at(0.1, held, None),
+			at(0.2, held, None),
+			at(0.4, held, None),
+			at(0.6, held, None),
+		],
+	);
+
+	assert_eq!(rebuilt, [0, 0, 0, 0, 1, 1]);
+}
@@ -0,0 +1,259 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Quoted {
+	/// Prints "hello" with a \ backslash.
+	#[control(bool)]
+	pub quoted: bool,
+	#[doc = "Tab\tseparated"]
+	#[control(bool)]
+	pub escaped: bool,
+}
+
+#[test]
+fn doc_comments_are_unescaped() {
+	let mut quoted = Quoted {
+		quoted: false,
+		escaped: false,
+	};
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| quoted.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&r#"Prints "hello" with a \ backslash."#.to_string()));
+	assert!(text.contains(&"Tab\tseparated".to_string()));
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(no_docs, missing_doc = "Undocumented", grid)]
+pub struct Quiet {
+	/// Whether to mute everything.
+	#[control]
+	pub muted: bool,
+	#[control]
+	pub volume: f32,
+}
+
+#[test]
+fn no_docs_hides_every_doc_label() {
+	let mut quiet = Quiet::default();
+	let frames = vec![egui::RawInput::default(), egui::RawInput::default()];
+	let output = common::run_frames(frames, |ui| quiet.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"Muted".to_string()), "{text:?}");
+	assert!(text.contains(&"Volume".to_string()), "{text:?}");
+	assert!(
+		!text.contains(&"Whether to mute everything.".to_string()),
+		"{text:?}"
+	);
+	assert!(!text.contains(&"Undocumented".to_string()), "{text:?}");
+}
+
+// The continuation lines of block comments are indented like the code, with tabs.
+#[allow(clippy::tabs_in_doc_comments)]
+#[derive(Debug, Default, ControlPanel)]
+pub struct Blocks {
+	/** two
+	line */
+	#[control]
+	pub plain: bool,
+	/**
+	 * Starred
+	 * lines.
+	 */
+	#[control]
+	pub starred: bool,
+}
+
+#[test]
+fn block_doc_comments_are_split_into_lines() {
+	let mut blocks = Blocks::default();
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| blocks.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"two line".to_string()), "{text:?}");
+	assert!(text.contains(&"Starred lines.".to_string()), "{text:?}");
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(docs = "short")]
+pub struct Camera {
+	/// Field of view.
+	///
+	/// Wider angles show more of the scene
+	/// but distort its edges.
+	#[control(slider(30.0..=120.0))]
+	pub fov: f32,
+	/// Whether the camera shakes.
+	#[control(bool)]
+	pub shake: bool,
+}
+
+#[test]
+fn short_docs_show_the_rest_on_hover() {
+	let mut camera = Camera::default();
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| camera.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(text.contains(&"Field of view.".to_string()), "{text:?}");
+	assert!(
+		text.contains(&"Whether the camera shakes.".to_string()),
+		"{text:?}"
+	);
+	assert!(!text.iter().any(|text| text.contains("Wider")), "{text:?}");
+
+	let hovering = vec![common::pointer(common::text_center(&output, "Field of view."), None); 2];
+	let output = common::run_frames(hovering, |ui| camera.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(
+		text.contains(&"Wider angles show more of the scene\nbut distort its edges.".to_string()),
+		"{text:?}"
+	);
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Tight {
+	/// Whether the game is paused.
+	#[control(bool)]
+	pub paused: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(label_gap = 12.0)]
+pub struct Spaced {
+	/// Whether the game is paused.
+	#[control(bool)]
+	pub paused: bool,
+	/// Whether the game is muted.
+	#[control(bool)]
+	pub muted: bool,
+}
+
+#[test]
+fn label_gap_spaces_every_description() {
+	let doc_left = |output: &egui::FullOutput, text: &str| common::text_rect(output, text).left();
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Tight::default().show(ui)
+	});
+	let tight = doc_left(&output, "Whether the game is paused.");
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Spaced::default().show(ui)
+	});
+
+	assert_eq!(
+		doc_left(&output, "Whether the game is paused."),
+		tight + 12.0
+	);
+	assert_eq!(
+		doc_left(&output, "Whether the game is muted."),
+		tight + 12.0
+	);
+}
+
+/// Settings for how the world is rendered.
+#[derive(Debug, Default, ControlPanel)]
+#[controls(show_description)]
+pub struct Described {
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+/// Settings for how the world is rendered.
+#[derive(Debug, Default, ControlPanel)]
+pub struct Undescribed {
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+#[test]
+fn show_description_renders_the_type_doc_above_the_fields() {
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Described::default().show(ui)
+	});
+	let text = common::rendered_text(&output);
+	assert_eq!(
+		text.first(),
+		Some(&"Settings for how the world is rendered.".to_string()),
+	);
+	assert!(
+		text.contains(&"Whether shadows are drawn.".to_string()),
+		"{text:?}"
+	);
+
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Undescribed::default().show(ui)
+	});
+	let text = common::rendered_text(&output);
+	assert!(
+		!text.contains(&"Settings for how the world is rendered.".to_string()),
+		"{text:?}"
+	);
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(missing_doc = "Undocumented")]
+pub struct Documented {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	/// How many frames are rendered ahead.
+	#[control]
+	pub frames_ahead: u8,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(missing_doc = "Undocumented")]
+pub struct PartlyDocumented {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	#[control]
+	pub frames_ahead: u8,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Undocumented {
+	#[control(bool)]
+	pub vsync: bool,
+}
+
+#[test]
+fn missing_doc_is_only_rendered_for_undocumented_fields() {
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Documented::default().show(ui)
+	});
+	let text = common::rendered_text(&output);
+	assert!(
+		text.contains(&"Whether vsync is on.".to_string()),
+		"{text:?}"
+	);
+	assert!(!text.contains(&"Undocumented".to_string()), "{text:?}");
+
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		PartlyDocumented::default().show(ui)
+	});
+	let text = common::rendered_text(&output);
+	assert!(
+		text.contains(&"Whether vsync is on.".to_string()),
+		"{text:?}"
+	);
+	assert_eq!(
+		text.iter().filter(|text| *text == "Undocumented").count(),
+		1,
+		"{text:?}"
+	);
+}
+
+#[test]
+fn undocumented_fields_get_no_label_by_default() {
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| {
+		Undocumented::default().show(ui)
+	});
+	let text = common::rendered_text(&output);
+
+	assert!(text.is_empty(), "{text:?}");
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Volume {
+	#[control(drag(clamp_range = 0..=100))]
+	pub loose: i32,
+	#[control(drag(clamp_range = 0..=100, clamp_existing))]
+	pub strict: i32,
+}
+
+/// The frames clicking the drag value showing `0` at `pos`, typing `text` into it
+/// and pressing enter.
+fn typing(pos: egui::Pos2, text: &str) -> Vec<egui::RawInput> {
+	let mut inputs = common::click(pos);
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text(text.to_string())],
+		..Default::default()
+	});
+	inputs.push(common::key(egui::Key::Enter));
+	inputs.push(Default::default());
+	inputs
+}
+
+/// Where the drag value of the `index`th row is.
+fn row(index: usize) -> egui::Pos2 {
+	let output = common::run_frames(vec![Default::default()], |ui| Volume::default().show(ui));
+	let zeros: Vec<_> = common::text_shapes(&output)
+		.into_iter()
+		.filter(|(text, _)| text == "0")
+		.collect();
+	zeros[index].1.center()
+}
+
+#[test]
+fn typed_values_are_kept_out_of_a_loose_range() {
+	let mut volume = Volume::default();
+	common::run_frames(typing(row(0), "250"), |ui| volume.show(ui));
+	assert_eq!(volume.loose, 250);
+}
+
+#[test]
+fn typed_values_are_clamped_with_clamp_existing() {
+	let mut volume = Volume::default();
+	common::run_frames(typing(row(1), "250"), |ui| volume.show(ui));
+	assert_eq!(volume.strict, 100);
+}
+
+#[test]
+fn existing_values_are_only_clamped_with_clamp_existing() {
+	let mut volume = Volume {
+		loose: -5,
+		strict: -5,
+	};
+	common::run_frames(vec![Default::default()], |ui| volume.show(ui));
+	assert_eq!(volume.loose, -5);
+	assert_eq!(volume.strict, 0);
+}
+
+#[test]
+fn dragging_stays_in_a_loose_range() {
+	let mut volume = Volume::default();
+	let start = row(0);
+	let mut inputs = vec![
+		common::pointer(start, None),
+		common::pointer(start, Some(true)),
+	];
+	for step in 1..=10 {
+		let pos = start - egui::vec2(10.0 * step as f32, 0.0);
+		inputs.push(common::pointer(pos, None));
+	}
+	common::run_frames(inputs, |ui| volume.show(ui));
+	assert_eq!(volume.loose, 0);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Ability {
+	#[control(slider_duration(0.0..=60.0))]
+	pub cooldown: Duration,
+	#[control(slider_duration(-5.0..=1.0))]
+	pub delay: Duration,
+}
+
+/// The left end of the slider in the top left corner.
+const LEFT_END: egui::Pos2 = egui::pos2(9.0, 15.0);
+
+#[test]
+fn durations_are_edited_in_seconds() {
+	let mut ability = Ability {
+		cooldown: Duration::from_secs(30),
+		delay: Duration::ZERO,
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| ability.show(ui));
+	assert!(common::rendered_text(&output).contains(&"30.0 s".to_string()));
+
+	common::run_frames(common::click(LEFT_END), |ui| ability.show(ui));
+	assert_eq!(ability.cooldown, Duration::ZERO);
+}
+
+#[test]
+fn negative_ranges_are_guarded() {
+	let mut ability = Ability {
+		cooldown: Duration::ZERO,
+		delay: Duration::from_millis(500),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| ability.show(ui));
+	let delay = common::text_rect(&output, "0.50 s");
+	// The left end of the second slider, left of its value.
+	let left_end = egui::pos2(LEFT_END.x, delay.center().y);
+	common::run_frames(common::click(left_end), |ui| ability.show(ui));
+
+	assert_eq!(ability.delay, Duration::ZERO);
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use bevy::ecs::entity::Entity;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Selection {
+	/// Selected.
+	#[control(readonly)]
+	pub selected: Entity,
+	/// Target.
+	#[control(entity_picker = "targets")]
+	pub target: Entity,
+	pub names: Vec<(Entity, String)>,
+}
+
+impl Selection {
+	fn targets(&self) -> &[(Entity, String)] {
+		&self.names
+	}
+}
+
+fn selection() -> Selection {
+	Selection {
+		selected: Entity::from_raw(7),
+		target: Entity::from_raw(1),
+		names: vec![
+			(Entity::from_raw(1), "Player".to_string()),
+			(Entity::from_raw(2), "Enemy".to_string()),
+		],
+	}
+}
+
+#[test]
+fn readonly_entities_display_their_index_and_generation() {
+	let mut selection = selection();
+	let output = common::run_frames(vec![Default::default()], |ui| selection.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(
+		text.contains(&format!("{:?}", Entity::from_raw(7))),
+		"{text:?}"
+	);
+}
+
+#[test]
+fn the_picker_shows_the_name_of_the_entity() {
+	let mut selection = selection();
+	let output = common::run_frames(vec![Default::default()], |ui| selection.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"Player".to_string()), "{text:?}");
+	assert!(!text.contains(&"Enemy".to_string()), "{text:?}");
+}
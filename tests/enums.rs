@@ -0,0 +1,121 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+pub enum Shape {
+	Circle {
+		/// The radius of the circle.
+		#[control(slider(0.0..=10.0))]
+		radius: f32,
+	},
+	Square(
+		/// Whether the square is filled.
+		#[control(bool)]
+		bool,
+	),
+}
+
+#[test]
+fn selecting_and_editing_a_variant() {
+	let mut shape = Shape::Circle { radius: 4.0 };
+
+	// Switching to the other variant constructs it with default data.
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| shape.show(ui));
+	let square = common::text_center(&output, "Square");
+	common::run_frames(common::click(square), |ui| shape.show(ui));
+	assert_eq!(shape, Shape::Square(false));
+
+	// The data of the selected variant is then editable beneath the selector.
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| shape.show(ui));
+	let checkbox = common::left_of(&output, "Whether the square is filled.");
+	let output = common::run_frames(common::click(checkbox), |ui| shape.show(ui));
+	assert_eq!(shape, Shape::Square(true));
+	assert!(!common::rendered_text(&output).contains(&"The radius of the circle.".to_string()));
+
+	// Re-rendering without input keeps the edited state.
+	common::run_frames(vec![egui::RawInput::default()], |ui| shape.show(ui));
+	assert_eq!(shape, Shape::Square(true));
+}
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+pub enum Size {
+	Scale(f32),
+	Named(String),
+}
+
+#[test]
+fn newtype_variants_edit_their_value() {
+	let mut size = Size::Scale(2.5);
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| size.show(ui));
+	assert!(common::rendered_text(&output).contains(&"2.5".to_string()));
+
+	// Switching initializes the value of the other variant from `Default`.
+	let named = common::text_center(&output, "Named");
+	let output = common::run_frames(common::click(named), |ui| size.show(ui));
+	assert_eq!(size, Size::Named(String::new()));
+	assert!(!common::rendered_text(&output).contains(&"2.5".to_string()));
+
+	// Its value is then edited with the inferred textbox.
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| size.show(ui));
+	// The group holding the textbox comes right below the selectors.
+	let named = common::text_rect(&output, "Named");
+	let text_edit = egui::pos2(named.left() + 20.0, named.bottom() + 18.0);
+	let mut inputs = common::click(text_edit);
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text("big".to_string())],
+		..Default::default()
+	});
+	common::run_frames(inputs, |ui| size.show(ui));
+	assert_eq!(size, Size::Named("big".to_string()));
+
+	// And switching back gives a default scale to drag.
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| size.show(ui));
+	let scale = common::text_center(&output, "Scale");
+	common::run_frames(common::click(scale), |ui| size.show(ui));
+	assert_eq!(size, Size::Scale(0.0));
+}
+
+#[derive(Debug, PartialEq, ControlPanel)]
+pub enum State {
+	Idle,
+	Running,
+	#[control(skip)]
+	Loading,
+}
+
+#[test]
+fn skipped_variants_are_only_shown_while_selected() {
+	let mut state = State::Idle;
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| state.show(ui));
+	let text = common::rendered_text(&output);
+	assert_eq!(text, ["Idle", "Running"]);
+
+	state = State::Loading;
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| state.show(ui));
+	let text = common::rendered_text(&output);
+	assert_eq!(text, ["Idle", "Running", "Loading"]);
+
+	// The skipped variant can still be left.
+	let idle = common::text_center(&output, "Idle");
+	common::run_frames(common::click(idle), |ui| state.show(ui));
+	assert_eq!(state, State::Idle);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ControlPanel)]
+pub enum Quality {
+	#[control(label = "Low quality")]
+	Low,
+	HighQuality,
+}
+
+#[test]
+fn variants_are_labelled_with_their_name_or_label() {
+	let mut quality = Quality::Low;
+	let output = common::run_frames(vec![egui::RawInput::default()], |ui| quality.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert_eq!(text, ["Low quality", "HighQuality"]);
+	assert_eq!(Quality::VARIANT_LABELS, ["Low quality", "HighQuality"]);
+}
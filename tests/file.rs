@@ -0,0 +1,68 @@
+mod common;
+
+use std::path::PathBuf;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Export {
+	#[control(file)]
+	pub path: PathBuf,
+	#[control(file)]
+	pub log: String,
+}
+
+#[test]
+fn paths_are_edited_as_text() {
+	let mut export = Export {
+		path: PathBuf::from("out"),
+		log: "log".to_string(),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| export.show(ui));
+	let path = common::text_rect(&output, "out").right_center() + egui::vec2(20.0, 0.0);
+	let log = common::text_rect(&output, "log").right_center() + egui::vec2(20.0, 0.0);
+
+	let mut inputs = common::click(path);
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text("/a.png".to_string())],
+		..Default::default()
+	});
+	common::run_frames(inputs, |ui| export.show(ui));
+	let mut inputs = common::click(log);
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text(".txt".to_string())],
+		..Default::default()
+	});
+	common::run_frames(inputs, |ui| export.show(ui));
+
+	assert_eq!(export.path, PathBuf::from("out/a.png"));
+	assert_eq!(export.log, "log.txt");
+}
+
+#[test]
+fn every_field_has_a_browse_button() {
+	let mut export = Export::default();
+	let output = common::run_frames(vec![Default::default()], |ui| export.show(ui));
+	let buttons = common::rendered_text(&output)
+		.into_iter()
+		.filter(|text| text == "Browse…")
+		.count();
+
+	assert_eq!(buttons, 2);
+}
+
+#[cfg(not(feature = "rfd"))]
+#[test]
+fn browsing_is_disabled_without_rfd() {
+	let mut export = Export::default();
+	let output = common::run_frames(vec![Default::default()], |ui| export.show(ui));
+	let browse = common::text_center(&output, "Browse…");
+	let mut changed = false;
+	common::run_frames(common::click(browse), |ui| {
+		changed |= export.ui(ui).changed()
+	});
+
+	assert!(!changed);
+	assert_eq!(export.path, PathBuf::new());
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(request_focus_first)]
+pub struct Form {
+	#[control(plot)]
+	pub history: Vec<f32>,
+	#[control(textbox)]
+	pub first_name: String,
+	#[control(textbox)]
+	pub last_name: String,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Unfocused {
+	#[control(textbox)]
+	pub name: String,
+}
+
+/// Where the text cursor of the focused text edit is drawn, if any.
+fn cursor(output: &egui::FullOutput) -> Option<egui::Pos2> {
+	output.platform_output.text_cursor_pos
+}
+
+#[test]
+fn the_first_editable_widget_is_focused() {
+	let mut form = Form::default();
+	let output = common::run_frames(vec![Default::default(); 2], |ui| form.show(ui));
+
+	assert!(cursor(&output).is_some());
+}
+
+#[test]
+fn focus_is_only_requested_on_opt_in() {
+	let mut unfocused = Unfocused::default();
+	let output = common::run_frames(vec![Default::default(); 2], |ui| unfocused.show(ui));
+
+	assert_eq!(cursor(&output), None);
+}
+
+#[test]
+fn tab_moves_down_through_the_fields() {
+	let mut form = Form::default();
+	let first = common::run_frames(vec![Default::default(); 2], |ui| form.show(ui));
+	let second = common::run_frames(
+		vec![
+			Default::default(),
+			Default::default(),
+			common::key(egui::Key::Tab),
+			Default::default(),
+		],
+		|ui| form.show(ui),
+	);
+
+	let (first, second) = (cursor(&first).unwrap(), cursor(&second).unwrap());
+	assert!(second.y > first.y, "{first:?} then {second:?}");
+}
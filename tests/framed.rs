@@ -0,0 +1,45 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Audio {
+	#[control]
+	pub muted: bool,
+}
+
+#[test]
+fn the_panel_is_rendered_inside_the_frame() {
+	let mut audio = Audio::default();
+	let plain = common::run_frames(vec![Default::default()], |ui| {
+		audio.ui(ui);
+	});
+	let mut rect = egui::Rect::NOTHING;
+	let framed = common::run_frames(vec![Default::default()], |ui| {
+		let frame = egui::Frame::none()
+			.fill(egui::Color32::RED)
+			.inner_margin(20.0);
+		rect = audio.ui_framed(ui, frame).rect;
+	});
+
+	let filled = framed.shapes.iter().any(|egui::epaint::ClippedShape(_, shape)| {
+		matches!(shape, egui::Shape::Rect(rect) if rect.fill == egui::Color32::RED)
+	});
+	assert!(filled);
+	assert!(framed.shapes.len() > plain.shapes.len());
+	assert!(rect.height() >= 40.0, "{rect:?}");
+}
+
+#[test]
+fn edits_inside_the_frame_are_reported() {
+	let mut audio = Audio::default();
+	let mut changed = false;
+	common::run_frames(common::click(egui::pos2(35.0, 35.0)), |ui| {
+		let frame = egui::Frame::none().inner_margin(20.0);
+		changed |= audio.ui_framed(ui, frame).changed();
+	});
+
+	assert!(audio.muted);
+	assert!(changed);
+}
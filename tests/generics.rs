@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Labelled<'a, T>
+where
+	T: egui::emath::Numeric,
+{
+	/// The value to tweak.
+	#[control(slider(T::from_f64(0.0)..=T::from_f64(1.0)))]
+	pub value: T,
+	/// A label for the value.
+	#[control(textbox)]
+	pub label: String,
+	pub source: &'a str,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Marked<T> {
+	/// The value to tweak.
+	#[control(slider(0.0..=1.0))]
+	pub value: f32,
+	#[control]
+	pub marker: PhantomData<T>,
+	#[control]
+	pub unit: (),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, strum::Display, strum::EnumIter, ControlPanel)]
+pub enum Choice<T: PartialEq + Copy> {
+	First,
+	Second(PhantomData<T>),
+}
+
+#[test]
+fn generic_types_render() {
+	let source = String::from("borrowed");
+	let mut labelled = Labelled::<f32> {
+		value: 0.5,
+		label: "label".to_string(),
+		source: &source,
+	};
+	let mut choice = Choice::<u8>::First;
+
+	let ctx = egui::Context::default();
+	let _ = ctx.run(egui::RawInput::default(), |ctx| {
+		egui::CentralPanel::default().show(ctx, |ui| {
+			labelled.show(ui);
+			choice.show(ui);
+		});
+	});
+
+	assert_eq!(labelled.value, 0.5);
+	assert_eq!(labelled.source, "borrowed");
+	assert_eq!(choice, Choice::First);
+}
+
+#[test]
+fn marker_fields_render_nothing() {
+	let mut marked = Marked::<u32>::default();
+	let ctx = egui::Context::default();
+	let output = ctx.run(egui::RawInput::default(), |ctx| {
+		egui::CentralPanel::default().show(ctx, |ui| marked.show(ui));
+	});
+
+	// The slider's value and its description, nothing for the markers.
+	assert_eq!(
+		output
+			.shapes
+			.iter()
+			.filter(|shape| matches!(shape.1, egui::Shape::Text(_)))
+			.count(),
+		2
+	);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+const BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Particles {
+	#[control(gradient)]
+	pub colors: Vec<([f32; 4], f32)>,
+}
+
+fn is_sorted(stops: &[([f32; 4], f32)]) -> bool {
+	stops.windows(2).all(|pair| pair[0].1 <= pair[1].1)
+}
+
+#[test]
+fn stops_are_sorted_by_position() {
+	let mut particles = Particles {
+		colors: vec![(RED, 0.8), (BLUE, 0.2)],
+	};
+	common::run_frames(vec![Default::default()], |ui| particles.show(ui));
+
+	assert_eq!(particles.colors, vec![(BLUE, 0.2), (RED, 0.8)]);
+}
+
+#[test]
+fn added_stops_stay_sorted() {
+	let mut particles = Particles {
+		colors: vec![(RED, 0.0), (BLUE, 1.0)],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| particles.show(ui));
+	let add = common::text_center(&output, "+");
+	common::run_frames(common::click(add), |ui| particles.show(ui));
+
+	assert_eq!(particles.colors.len(), 3);
+	assert!(is_sorted(&particles.colors), "{:?}", particles.colors);
+	assert_eq!(particles.colors[1].1, 0.5);
+}
+
+#[test]
+fn no_stops_render_an_empty_bar() {
+	let mut particles = Particles::default();
+	common::run_frames(vec![Default::default()], |ui| particles.show(ui));
+
+	assert!(particles.colors.is_empty());
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+/// The frames rendering a grid, which is only painted once it knows its column widths.
+fn frames() -> Vec<egui::RawInput> {
+	vec![Default::default(), Default::default()]
+}
+
+#[derive(Debug, ControlPanel)]
+#[controls(grid)]
+pub struct Camera {
+	#[control]
+	pub fov: f32,
+	#[control]
+	pub far_plane_distance: u32,
+}
+
+#[derive(Debug, ControlPanel)]
+#[controls(grid, align_widgets = "right")]
+pub struct RightCamera {
+	#[control]
+	pub fov: f32,
+	#[control]
+	pub far_plane_distance: u32,
+}
+
+#[test]
+fn names_and_widgets_get_a_column_each() {
+	let mut camera = Camera {
+		fov: 1.5,
+		far_plane_distance: 20,
+	};
+	let output = common::run_frames(frames(), |ui| camera.show(ui));
+	let fov = common::text_rect(&output, "Fov");
+	let far = common::text_rect(&output, "Far plane distance");
+	let fov_value = common::text_center(&output, "1.5");
+	let far_value = common::text_center(&output, "20");
+
+	assert_eq!(fov.left(), far.left());
+	assert!(fov_value.x > far.right(), "{fov_value:?} {far:?}");
+	assert!(
+		(fov_value.x - far_value.x).abs() < 1.0,
+		"{fov_value:?} {far_value:?}"
+	);
+}
+
+#[test]
+fn widgets_can_be_right_aligned_in_their_column() {
+	let mut left = Camera {
+		fov: 1.5,
+		far_plane_distance: 20,
+	};
+	let mut right = RightCamera {
+		fov: 1.5,
+		far_plane_distance: 20,
+	};
+	let output = common::run_frames(frames(), |ui| left.show(ui));
+	let left_value = common::text_center(&output, "1.5");
+	let output = common::run_frames(frames(), |ui| right.show(ui));
+	let fov = common::text_rect(&output, "Fov");
+	let fov_value = common::text_center(&output, "1.5");
+	let far_value = common::text_center(&output, "20");
+
+	assert!(
+		fov_value.x > left_value.x + 100.0,
+		"{fov_value:?} {left_value:?}"
+	);
+	assert!(
+		(fov.center().y - fov_value.y).abs() < 1.0,
+		"{fov:?} {fov_value:?}"
+	);
+	assert!(
+		(fov_value.x - far_value.x).abs() < 1.0,
+		"{fov_value:?} {far_value:?}"
+	);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(docs = "popup")]
+pub struct Tuning {
+	/// How quickly the camera follows the player.
+	///
+	/// Higher values feel snappier.
+	#[control(slider(0.0..=1.0))]
+	pub stiffness: f32,
+	/// Whether the camera shakes on impacts.
+	#[control(bool)]
+	pub shake: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Inline {
+	/// Shown right away.
+	#[control(bool)]
+	pub inline: bool,
+	/// Shown on request.
+	#[control(bool, help)]
+	pub hidden: bool,
+}
+
+/// Where every "?" button is, top to bottom.
+fn help_buttons(output: &egui::FullOutput) -> Vec<egui::Pos2> {
+	common::text_shapes(output)
+		.into_iter()
+		.filter(|(text, _)| text == "?")
+		.map(|(_, rect)| rect.center())
+		.collect()
+}
+
+/// The frames clicking the button at `pos`, and the one the popup it opens is painted in,
+/// since egui only sizes new areas in their first frame.
+fn opening(pos: egui::Pos2) -> Vec<egui::RawInput> {
+	let mut inputs = common::click(pos);
+	inputs.push(Default::default());
+	inputs
+}
+
+#[test]
+fn docs_open_in_a_popup() {
+	let mut tuning = Tuning::default();
+	let output = common::run_frames(vec![Default::default()], |ui| tuning.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(
+		!text.iter().any(|text| text.contains("snappier")),
+		"{text:?}"
+	);
+
+	let buttons = help_buttons(&output);
+	let output = common::run_frames(opening(buttons[0]), |ui| tuning.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(
+		text.contains(
+			&"How quickly the camera follows the player.\n\nHigher values feel snappier."
+				.to_string()
+		),
+		"{text:?}"
+	);
+}
+
+#[test]
+fn only_one_popup_is_open_at_a_time() {
+	let mut tuning = Tuning::default();
+	let output = common::run_frames(vec![Default::default()], |ui| tuning.show(ui));
+	let buttons = help_buttons(&output);
+	// Open the lower popup first, so it doesn't cover the upper button.
+	let mut inputs = opening(buttons[1]);
+	inputs.extend(opening(buttons[0]));
+	let output = common::run_frames(inputs, |ui| tuning.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(
+		text.iter().any(|text| text.contains("snappier")),
+		"{text:?}"
+	);
+	assert!(
+		!text.contains(&"Whether the camera shakes on impacts.".to_string()),
+		"{text:?}"
+	);
+}
+
+#[test]
+fn help_is_per_field() {
+	let mut inline = Inline::default();
+	let output = common::run_frames(vec![Default::default()], |ui| inline.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"Shown right away.".to_string()), "{text:?}");
+	assert!(!text.contains(&"Shown on request.".to_string()), "{text:?}");
+	assert_eq!(help_buttons(&output).len(), 1);
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(docs_as_help_icon)]
+pub struct Icons {
+	/// How far the camera stays behind the player.
+	#[control(slider(0.0..=10.0))]
+	pub distance: f32,
+	/// Whether the camera collides with walls.
+	#[control(bool)]
+	pub collide: bool,
+	/// Still opened with a click.
+	#[control(bool, help)]
+	pub popup: bool,
+}
+
+#[test]
+fn docs_are_the_hover_text_of_help_icons() {
+	let mut icons = Icons::default();
+	let output = common::run_frames(vec![Default::default()], |ui| icons.show(ui));
+	let text = common::rendered_text(&output);
+	let icons_shown: Vec<_> = common::text_shapes(&output)
+		.into_iter()
+		.filter(|(text, _)| text == "ⓘ")
+		.map(|(_, rect)| rect.center())
+		.collect();
+	assert_eq!(icons_shown.len(), 2, "{text:?}");
+	assert_eq!(help_buttons(&output).len(), 1);
+	assert!(
+		!text
+			.iter()
+			.any(|text| text.contains("How far") || text.contains("collides")),
+		"{text:?}"
+	);
+
+	let hovering = vec![common::pointer(icons_shown[1], None); 2];
+	let output = common::run_frames(hovering, |ui| icons.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(
+		text.contains(&"Whether the camera collides with walls.".to_string()),
+		"{text:?}"
+	);
+	assert!(
+		!text.iter().any(|text| text.contains("How far")),
+		"{text:?}"
+	);
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+macro_rules! panel {
+	($name:ident { $($field:ident: $ty:ty),* }) => {
+		#[derive(Debug, Default, ControlPanel)]
+		pub struct $name {
+			$(
+				#[control]
+				pub $field: $ty,
+			)*
+		}
+	};
+}
+
+panel!(Generated {
+	ui: bool,
+	response: f32,
+	widgets: u8,
+	range: i32
+});
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Toggles {
+	/// Whether the overlay is shown.
+	#[control(bool)]
+	pub ui: bool,
+}
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+pub enum Layer {
+	Hud {
+		#[control]
+		ui: bool,
+		#[control]
+		response: f32,
+	},
+	World(u8),
+}
+
+#[test]
+fn a_field_named_ui_toggles() {
+	let mut toggles = Toggles::default();
+	let output = common::run_frames(vec![Default::default()], |ui| toggles.show(ui));
+	let pos = common::left_of(&output, "Whether the overlay is shown.");
+	common::run_frames(common::click(pos), |ui| toggles.show(ui));
+	assert!(toggles.ui);
+}
+
+#[test]
+fn fields_named_like_locals_render() {
+	let mut generated = Generated::default();
+	let mut layer = Layer::Hud {
+		ui: true,
+		response: 0.5,
+	};
+	let output = common::run_frames(vec![Default::default(); 2], |ui| {
+		generated.show(ui);
+		layer.show(ui);
+	});
+	let text = common::rendered_text(&output);
+	assert_eq!(
+		text.iter().filter(|text| *text == "0").count(),
+		3,
+		"{text:?}"
+	);
+	assert!(text.contains(&"0.5".to_string()), "{text:?}");
+}
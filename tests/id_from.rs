@@ -0,0 +1,58 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Item {
+	pub uuid: u64,
+	#[control(textbox, id_from = "uuid")]
+	pub name: String,
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Inventory {
+	#[control]
+	pub items: Vec<Item>,
+}
+
+fn item(uuid: u64, name: &str) -> Item {
+	Item {
+		uuid,
+		name: name.to_string(),
+	}
+}
+
+#[test]
+fn widget_state_follows_the_item_when_reordered() {
+	let mut inventory = Inventory {
+		items: vec![item(1, "sword"), item(2, "shield")],
+	};
+	let ctx = egui::Context::default();
+	let run = |inventory: &mut Inventory, input: egui::RawInput| {
+		ctx.run(input, |ctx| {
+			egui::CentralPanel::default().show(ctx, |ui| inventory.show(ui));
+		})
+	};
+
+	// Focus the text edit of the first item.
+	let output = run(&mut inventory, Default::default());
+	let sword = common::text_rect(&output, "sword").right_center() + egui::vec2(20.0, 0.0);
+	for input in common::click(sword) {
+		run(&mut inventory, input);
+	}
+
+	// Typing after the items swapped places still edits the focused item.
+	inventory.items.swap(0, 1);
+	run(&mut inventory, Default::default());
+	run(
+		&mut inventory,
+		egui::RawInput {
+			events: vec![egui::Event::Text("s".to_string())],
+			..Default::default()
+		},
+	);
+
+	assert_eq!(inventory.items[0].name, "shield");
+	assert_eq!(inventory.items[1].name, "swords");
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use bevy::prelude::Color;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, PartialEq, ControlPanel)]
+pub enum Quality {
+	#[default]
+	Low,
+	High,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Audio {
+	/// Whether the music plays.
+	#[control]
+	pub music: bool,
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Settings {
+	#[control]
+	pub scale: f32,
+	#[control]
+	pub count: u8,
+	#[control]
+	pub fullscreen: bool,
+	#[control]
+	pub name: String,
+	#[control]
+	pub tint: Color,
+	#[control]
+	pub quality: Quality,
+	#[control]
+	pub audio: Audio,
+	/// Explicit kinds still win over the inferred one.
+	#[control(slider(0..=10))]
+	pub lives: u8,
+}
+
+impl Default for Settings {
+	fn default() -> Self {
+		Self {
+			scale: 1.5,
+			count: 7,
+			fullscreen: false,
+			name: "player one".to_string(),
+			tint: Color::WHITE,
+			quality: Quality::Low,
+			audio: Audio::default(),
+			lives: 3,
+		}
+	}
+}
+
+fn rendered(settings: &mut Settings) -> Vec<String> {
+	let output = common::run_frames(vec![Default::default()], |ui| settings.show(ui));
+	common::rendered_text(&output)
+}
+
+#[test]
+fn numbers_get_drag_values() {
+	let text = rendered(&mut Settings::default());
+	assert!(text.contains(&"1.5".to_string()), "{text:?}");
+	assert!(text.contains(&"7".to_string()), "{text:?}");
+}
+
+#[test]
+fn strings_get_a_textbox() {
+	let text = rendered(&mut Settings::default());
+	assert!(text.contains(&"player one".to_string()), "{text:?}");
+}
+
+#[test]
+fn other_types_render_their_own_panel() {
+	let text = rendered(&mut Settings::default());
+	assert!(text.contains(&"Low".to_string()), "{text:?}");
+	assert!(text.contains(&"High".to_string()), "{text:?}");
+	assert!(
+		text.contains(&"Whether the music plays.".to_string()),
+		"{text:?}"
+	);
+}
+
+#[test]
+fn bools_get_a_checkbox() {
+	let mut audio = Audio::default();
+	let output = common::run_frames(vec![Default::default()], |ui| audio.show(ui));
+	let label = common::left_of(&output, "Whether the music plays.");
+	common::run_frames(common::click(label), |ui| audio.show(ui));
+	assert!(audio.music);
+}
+
+#[test]
+fn explicit_kinds_override_inference() {
+	let text = rendered(&mut Settings::default());
+	assert!(text.contains(&"3".to_string()), "{text:?}");
+	assert!(text.contains(&"Explicit kinds still win over the inferred one.".to_string()));
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "inspector")]
+
+mod common;
+
+use std::any::TypeId;
+
+use bevy::reflect::{Reflect, TypeRegistryInternal};
+use bevy_egui_controls::ControlPanel;
+use bevy_inspector_egui::reflect_inspector;
+
+#[derive(Default, Reflect, ControlPanel)]
+pub struct Settings {
+	/// How loud it is.
+	#[control(slider(0.0..=1.0))]
+	pub volume: f32,
+}
+
+#[test]
+fn the_inspector_shows_the_generated_ui() {
+	let mut registry = TypeRegistryInternal::default();
+	registry.register::<Settings>();
+	registry
+		.get_mut(TypeId::of::<Settings>())
+		.unwrap()
+		.insert(Settings::inspector_egui_impl());
+
+	let mut settings = Settings::default();
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		reflect_inspector::ui_for_value(&mut settings, ui, &registry);
+	});
+
+	assert!(common::rendered_text(&output).contains(&"How loud it is.".to_string()));
+}
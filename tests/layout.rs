@@ -0,0 +1,79 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Stacked {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(layout = "left_to_right")]
+pub struct Toolbar {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(layout = "right_to_left")]
+pub struct RightToolbar {
+	/// Whether vsync is on.
+	#[control(bool)]
+	pub vsync: bool,
+	/// Whether shadows are drawn.
+	#[control(bool)]
+	pub shadows: bool,
+}
+
+const VSYNC: &str = "Whether vsync is on.";
+const SHADOWS: &str = "Whether shadows are drawn.";
+
+#[test]
+fn rows_are_stacked_by_default() {
+	let output = common::run_frames(vec![Default::default()], |ui| Stacked::default().show(ui));
+	let vsync = common::text_rect(&output, VSYNC);
+	let shadows = common::text_rect(&output, SHADOWS);
+
+	assert!(vsync.bottom() <= shadows.top(), "{vsync:?} {shadows:?}");
+	assert!(
+		(vsync.left() - shadows.left()).abs() < 2.0,
+		"{vsync:?} {shadows:?}"
+	);
+}
+
+#[test]
+fn left_to_right_puts_the_rows_side_by_side() {
+	let output = common::run_frames(vec![Default::default()], |ui| Toolbar::default().show(ui));
+	let vsync = common::text_rect(&output, VSYNC);
+	let shadows = common::text_rect(&output, SHADOWS);
+
+	assert!(vsync.right() < shadows.left(), "{vsync:?} {shadows:?}");
+	assert!(
+		(vsync.center().y - shadows.center().y).abs() < 3.0,
+		"{vsync:?} {shadows:?}"
+	);
+}
+
+#[test]
+fn right_to_left_starts_from_the_right_edge() {
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		RightToolbar::default().show(ui)
+	});
+	let vsync = common::text_rect(&output, VSYNC);
+	let shadows = common::text_rect(&output, SHADOWS);
+
+	assert!(shadows.right() < vsync.left(), "{vsync:?} {shadows:?}");
+	assert!(
+		(vsync.center().y - shadows.center().y).abs() < 3.0,
+		"{vsync:?} {shadows:?}"
+	);
+}
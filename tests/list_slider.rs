@@ -0,0 +1,48 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Mixer {
+	#[control(list_slider(0.0..=1.0))]
+	pub channels: [f32; 4],
+	#[control(list_slider(0..=10, clamp))]
+	pub sends: Vec<u8>,
+}
+
+#[test]
+fn every_element_gets_an_identically_ranged_slider() {
+	let mut mixer = Mixer {
+		channels: [0.1, 0.2, 0.3, 0.4],
+		sends: vec![],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| mixer.show(ui));
+	let text = common::rendered_text(&output);
+	for value in ["0.10", "0.20", "0.30", "0.40"] {
+		assert!(text.contains(&value.to_string()), "{text:?}");
+	}
+
+	// Clicking the right end of every slider gives every channel the same value.
+	for index in ["0", "1", "2", "3"] {
+		let label = common::text_rect(&output, index);
+		let end = egui::pos2(label.right() + 8.0 + 99.0, label.center().y);
+		common::run_frames(common::click(end), |ui| mixer.show(ui));
+	}
+	assert_eq!(mixer.channels, [1.0; 4]);
+}
+
+#[test]
+fn elements_are_clamped_one_by_one() {
+	let mut mixer = Mixer {
+		channels: [0.0; 4],
+		sends: vec![3, 20],
+	};
+	let mut changed = false;
+	common::run_frames(vec![Default::default()], |ui| {
+		changed |= mixer.ui_changed(ui)
+	});
+
+	assert_eq!(mixer.sends, [3, 10]);
+	assert!(changed);
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use std::sync::{Arc, Mutex, RwLock};
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Physics {
+	/// Whether bodies collide.
+	#[control(bool)]
+	pub collisions: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Shared {
+	#[control(lock, nested)]
+	pub physics: Arc<Mutex<Physics>>,
+	/// The speed of the simulation.
+	#[control(lock, slider(0.0..=4.0))]
+	pub speed: RwLock<f32>,
+}
+
+#[test]
+fn locked_fields_are_edited_through_the_guard() {
+	let mut shared = Shared::default();
+	let physics = shared.physics.clone();
+	let output = common::run_frames(vec![Default::default()], |ui| shared.show(ui));
+	assert!(common::rendered_text(&output).contains(&"The speed of the simulation.".to_string()));
+
+	let checkbox = common::left_of(&output, "Whether bodies collide.");
+	common::run_frames(common::click(checkbox), |ui| shared.show(ui));
+
+	assert!(physics.lock().unwrap().collisions);
+}
+
+#[test]
+fn poisoned_locks_render_an_error() {
+	let mut shared = Shared::default();
+	let physics = shared.physics.clone();
+	std::thread::spawn(move || {
+		let _guard = physics.lock().unwrap();
+		panic!("poison the lock");
+	})
+	.join()
+	.unwrap_err();
+
+	let output = common::run_frames(vec![Default::default()], |ui| shared.show(ui));
+	assert!(common::rendered_text(&output).contains(&"poisoned lock".to_string()));
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(method = "render_settings")]
+pub struct Audio {
+	/// Whether the music plays.
+	#[control]
+	pub music: bool,
+}
+
+impl Audio {
+	/// An unrelated method the generated one would otherwise collide with.
+	pub fn ui(&self) -> &'static str {
+		"audio"
+	}
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Settings {
+	#[control(with = "Audio::render_settings")]
+	pub audio: Audio,
+}
+
+#[test]
+fn the_method_can_be_renamed() {
+	let mut audio = Audio::default();
+	let mut changed = false;
+	let output = common::run_frames(vec![Default::default()], |ui| {
+		changed |= audio.render_settings(ui).changed();
+	});
+
+	assert!(!changed);
+	assert_eq!(audio.ui(), "audio");
+	assert!(common::rendered_text(&output).contains(&"Whether the music plays.".to_string()));
+}
+
+#[test]
+fn renamed_panels_can_be_nested_with_with() {
+	let mut settings = Settings::default();
+	let output = common::run_frames(vec![Default::default()], |ui| settings.show(ui));
+	let checkbox = common::left_of(&output, "Whether the music plays.");
+	let mut actions = vec![];
+	common::run_frames(common::click(checkbox), |ui| {
+		actions.push(settings.ui_changed(ui));
+	});
+
+	assert!(settings.audio.music);
+	assert_eq!(actions, [false, false, true]);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Clone, Copy, PartialEq, ControlPanel)]
+pub enum Layer {
+	Terrain,
+	Water,
+	Roads,
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Map {
+	/// Layers drawn on the map.
+	#[control(multiselect)]
+	pub layers: Vec<Layer>,
+}
+
+#[test]
+fn every_variant_gets_a_checkbox() {
+	let mut map = Map {
+		layers: vec![Layer::Water],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| map.show(ui));
+	let text = common::rendered_text(&output);
+
+	for label in ["Terrain", "Water", "Roads"] {
+		assert!(text.contains(&label.to_string()), "{text:?}");
+	}
+}
+
+#[test]
+fn checking_appends_and_unchecking_removes() {
+	let mut map = Map {
+		layers: vec![Layer::Water],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| map.show(ui));
+	let roads = common::left_of(&output, "Roads");
+	let water = common::left_of(&output, "Water");
+
+	let mut changed = false;
+	common::run_frames(common::click(roads), |ui| changed |= map.ui(ui).changed());
+	assert_eq!(map.layers, [Layer::Water, Layer::Roads]);
+	assert!(changed);
+
+	common::run_frames(common::click(water), |ui| map.show(ui));
+	assert_eq!(map.layers, [Layer::Roads]);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Toggle {
+	#[control(bool, on_change = "recompute")]
+	pub enabled: bool,
+	pub recomputed: usize,
+}
+
+impl Toggle {
+	fn recompute(&mut self) {
+		self.recomputed += 1;
+	}
+}
+
+/// Where the checkbox in the top left corner is.
+const CHECKBOX: egui::Pos2 = egui::pos2(15.0, 15.0);
+
+#[test]
+fn editing_calls_the_callback() {
+	let mut toggle = Toggle::default();
+	common::run_frames(common::click(CHECKBOX), |ui| toggle.show(ui));
+
+	assert!(toggle.enabled);
+	assert_eq!(toggle.recomputed, 1);
+}
+
+#[test]
+fn leaving_it_alone_does_not() {
+	let mut toggle = Toggle::default();
+	common::run_frames(
+		vec![
+			common::pointer(CHECKBOX, None),
+			common::pointer(CHECKBOX, None),
+		],
+		|ui| toggle.show(ui),
+	);
+
+	assert!(!toggle.enabled);
+	assert_eq!(toggle.recomputed, 0);
+}
+
+#[test]
+fn ui_changed_reports_edits() {
+	let mut toggle = Toggle::default();
+	let mut changed = vec![];
+	common::run_frames(common::click(CHECKBOX), |ui| {
+		changed.push(toggle.ui_changed(ui))
+	});
+
+	assert_eq!(changed, vec![false, false, true]);
+}
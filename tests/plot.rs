@@ -0,0 +1,32 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Dashboard {
+	#[control(plot(height = 60.0, label = "fps history"))]
+	pub fps: Vec<f32>,
+	#[control(plot)]
+	pub samples: Vec<u8>,
+}
+
+#[test]
+fn plots_are_read_only() {
+	let mut dashboard = Dashboard {
+		fps: vec![60.0, 59.5, 61.0],
+		samples: vec![1, 2, 3],
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| dashboard.show(ui));
+
+	assert!(common::rendered_text(&output).contains(&"fps history".to_string()));
+	assert_eq!(dashboard.fps, vec![60.0, 59.5, 61.0]);
+	assert_eq!(dashboard.samples, vec![1, 2, 3]);
+}
+
+#[test]
+fn empty_series_render_an_empty_plot() {
+	let mut dashboard = Dashboard::default();
+	common::run_frames(vec![Default::default()], |ui| dashboard.show(ui));
+
+	assert!(dashboard.fps.is_empty());
+}
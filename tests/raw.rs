@@ -0,0 +1,29 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Layer {
+	#[control(slider(0.0..=1.0), raw(.text("opacity").trailing_fill(true)))]
+	pub opacity: f32,
+	#[control(raw(egui::DragValue::new(&mut self.depth).suffix(" m")))]
+	pub depth: f32,
+	#[control(raw(.hint_text("untitled")))]
+	pub name: String,
+}
+
+#[test]
+fn raw_tokens_are_spliced_into_the_widget() {
+	let mut layer = Layer {
+		opacity: 0.5,
+		depth: 2.0,
+		name: String::new(),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| layer.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"opacity".to_string()), "{text:?}");
+	assert!(text.contains(&"2 m".to_string()), "{text:?}");
+	assert!(text.contains(&"untitled".to_string()), "{text:?}");
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use bevy_egui_controls::ControlPanel;
+
+/// A distance, which only its inner number can display.
+#[derive(Debug, Clone, Copy)]
+pub struct Meters(pub f32);
+
+#[derive(Debug)]
+pub struct Named {
+	pub name: &'static str,
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Stats {
+	/// Meters travelled.
+	#[control(readonly, display_via = "0")]
+	pub travelled: Meters,
+	/// Kills.
+	#[control(readonly)]
+	pub kills: u32,
+	/// Current map.
+	#[control(readonly, display_via = "name")]
+	pub map: Named,
+}
+
+#[test]
+fn readonly_controls_display_the_inner_value() {
+	let mut stats = Stats {
+		travelled: Meters(3.5),
+		kills: 12,
+		map: Named { name: "Harbor" },
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| stats.show(ui));
+	let text = common::rendered_text(&output);
+
+	for shown in ["3.5", "12", "Harbor"] {
+		assert!(text.contains(&shown.to_string()), "{text:?}");
+	}
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Derived {
+	/// Average frame time.
+	#[control(readonly, fmt = "{:.2} ms")]
+	pub frame_time: f64,
+	/// Meters travelled.
+	#[control(readonly, display_via = "0", fmt = "{:.1}")]
+	pub travelled: Meters,
+}
+
+#[test]
+fn fmt_formats_the_displayed_value() {
+	let mut derived = Derived {
+		frame_time: 16.6666,
+		travelled: Meters(3.27),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| derived.show(ui));
+	let text = common::rendered_text(&output);
+
+	for shown in ["16.67 ms", "3.3"] {
+		assert!(text.contains(&shown.to_string()), "{text:?}");
+	}
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Timers {
+	/// Last save.
+	#[control(readonly, since)]
+	pub saved: Instant,
+}
+
+#[test]
+fn since_displays_the_elapsed_time() {
+	let mut timers = Timers {
+		saved: Instant::now() - Duration::from_secs(3),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| timers.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"3.0s ago".to_string()), "{text:?}");
+	assert!(output.repaint_after <= Duration::from_millis(100));
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Padded {
+	/// Score.
+	#[control(readonly, fmt = "{:$>6}")]
+	pub score: u32,
+}
+
+#[test]
+fn fmt_fills_with_any_character() {
+	let mut padded = Padded { score: 42 };
+	let output = common::run_frames(vec![Default::default()], |ui| padded.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"$$$$42".to_string()), "{text:?}");
+}
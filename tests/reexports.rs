@@ -0,0 +1,57 @@
+use bevy_egui_controls::ControlPanel;
+
+/// Stands in for a framework crate that re-exports its dependencies.
+mod framework {
+	pub use bevy_egui::egui as gui;
+	pub use strum as iter;
+}
+
+use framework::gui;
+
+#[derive(Debug, ControlPanel)]
+#[controls(egui_crate = crate::framework::gui)]
+pub struct Settings {
+	/// Whether the thing is enabled.
+	#[control(bool)]
+	pub enabled: bool,
+	/// How much of the thing there is.
+	#[control(slider(0.0..=1.0, fill))]
+	pub amount: f32,
+}
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+#[controls(egui_crate = crate::framework::gui, strum_crate = crate::framework::iter)]
+pub enum Mode {
+	Fast,
+	Slow(u8),
+}
+
+#[derive(Debug, PartialEq, strum::EnumIter, ControlPanel)]
+#[controls(strum_path = "crate::framework::iter")]
+pub enum Quality {
+	Low,
+	Custom(u8),
+}
+
+#[test]
+fn renders_through_reexported_paths() {
+	let mut settings = Settings {
+		enabled: true,
+		amount: 0.25,
+	};
+	let mut mode = Mode::Slow(3);
+	let mut quality = Quality::Custom(2);
+
+	let ctx = gui::Context::default();
+	let _ = ctx.run(gui::RawInput::default(), |ctx| {
+		gui::CentralPanel::default().show(ctx, |ui| {
+			settings.show(ui);
+			mode.show(ui);
+			quality.show(ui);
+		});
+	});
+
+	assert!(settings.enabled);
+	assert_eq!(mode, Mode::Slow(3));
+	assert_eq!(quality, Quality::Custom(2));
+}
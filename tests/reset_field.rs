@@ -0,0 +1,45 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+#[controls(reset_field)]
+pub struct Movement {
+	/// How fast the player moves.
+	#[control(slider(0.0..=10.0))]
+	pub move_speed: f32,
+	/// Whether the player can jump.
+	#[control(bool)]
+	pub can_jump: bool,
+}
+
+impl Default for Movement {
+	fn default() -> Self {
+		Self {
+			move_speed: 5.0,
+			can_jump: true,
+		}
+	}
+}
+
+#[test]
+fn known_fields_are_reset_to_their_default() {
+	let mut movement = Movement {
+		move_speed: 9.0,
+		can_jump: false,
+	};
+
+	assert!(movement.reset_field("move_speed"));
+	assert_eq!(movement.move_speed, 5.0);
+	assert!(!movement.can_jump);
+}
+
+#[test]
+fn unknown_fields_are_left_alone() {
+	let mut movement = Movement {
+		move_speed: 9.0,
+		can_jump: false,
+	};
+
+	assert!(!movement.reset_field("jump_height"));
+	assert_eq!(movement.move_speed, 9.0);
+	assert!(!movement.can_jump);
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize, ControlPanel)]
+#[controls(labels_from_serde)]
+pub struct Config {
+	#[control(slider(0.0..=10.0))]
+	#[serde(rename = "speed-limit")]
+	pub max_speed: f32,
+	#[control]
+	#[serde(default, rename(serialize = "vsync", deserialize = "v_sync"))]
+	pub use_vsync: bool,
+	#[control]
+	pub window_title: String,
+}
+
+#[test]
+fn renamed_fields_are_labelled_with_their_serde_name() {
+	let mut config = Config::default();
+	let output = common::run_frames(vec![Default::default()], |ui| config.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"speed-limit".to_string()), "{text:?}");
+	assert!(text.contains(&"vsync".to_string()), "{text:?}");
+	assert!(text.contains(&"Window title".to_string()), "{text:?}");
+	assert!(!text.contains(&"max_speed".to_string()), "{text:?}");
+}
+
+#[test]
+fn labels_come_before_the_widget() {
+	let mut config = Config::default();
+	let output = common::run_frames(vec![Default::default()], |ui| config.show(ui));
+	let label = common::text_rect(&output, "speed-limit");
+	let value = common::text_rect(&output, "0");
+
+	assert!(label.right() <= value.left(), "{label:?} {value:?}");
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Toggles {
+	#[control(bool, show_value)]
+	pub enabled: bool,
+	#[control(drag)]
+	pub steps: u32,
+}
+
+#[derive(Debug, ControlPanel)]
+#[controls(show_values)]
+pub struct Stepper {
+	#[control(bool)]
+	pub enabled: bool,
+	#[control(textbox)]
+	pub name: String,
+}
+
+#[test]
+fn values_are_shown_on_opt_in() {
+	let mut toggles = Toggles {
+		enabled: true,
+		steps: 3,
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| toggles.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"true".to_string()), "{text:?}");
+	assert_eq!(
+		text.iter().filter(|text| *text == "3").count(),
+		1,
+		"{text:?}"
+	);
+}
+
+#[test]
+fn show_values_applies_to_every_field() {
+	let mut stepper = Stepper {
+		enabled: false,
+		name: "left".to_string(),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| stepper.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"false".to_string()), "{text:?}");
+	assert!(text.contains(&"\"left\"".to_string()), "{text:?}");
+}
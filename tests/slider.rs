@@ -0,0 +1,69 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Levels {
+	#[control(slider)]
+	pub level: i8,
+}
+
+/// The left end of the slider in the top left corner.
+const LEFT_END: egui::Pos2 = egui::pos2(9.0, 15.0);
+
+#[test]
+fn a_bare_slider_spans_the_integer_type() {
+	let mut levels = Levels { level: 100 };
+	let output = common::run_frames(vec![Default::default()], |ui| levels.show(ui));
+	assert!(common::rendered_text(&output).contains(&"100".to_string()));
+
+	common::run_frames(common::click(LEFT_END), |ui| levels.show(ui));
+	assert_eq!(levels.level, i8::MIN);
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Health {
+	#[control(slider(1.0..=200.0))]
+	pub max_health: f32,
+	#[control(slider(0.0..=self.max_health, clamp))]
+	pub current_health: f32,
+	#[control(slider(0.0..=self.max_health))]
+	pub shield: f32,
+}
+
+#[test]
+fn ranges_can_refer_to_other_fields() {
+	let mut health = Health {
+		max_health: 100.0,
+		current_health: 150.0,
+		shield: 150.0,
+	};
+	common::run_frames(vec![Default::default()], |ui| health.show(ui));
+
+	assert_eq!(health.current_health, 100.0);
+	assert_eq!(health.shield, 150.0);
+}
+
+#[derive(Debug, ControlPanel)]
+#[controls(grid)]
+pub struct Opacity {
+	/// How see-through the window is.
+	#[control(slider(0.0..=1.0, text = "alpha"))]
+	pub opacity: f32,
+}
+
+#[test]
+fn slider_text_is_attached_after_the_value() {
+	let mut opacity = Opacity { opacity: 0.5 };
+	let frames = vec![Default::default(), Default::default()];
+	let output = common::run_frames(frames, |ui| opacity.show(ui));
+	let name = common::text_rect(&output, "Opacity");
+	let value = common::text_rect(&output, "0.50");
+	let text = common::text_rect(&output, "alpha");
+	let doc = common::text_rect(&output, "How see-through the window is.");
+
+	assert!(name.right() < value.left(), "{name:?} {value:?}");
+	assert!(value.right() < text.left(), "{value:?} {text:?}");
+	assert!(text.right() < doc.left(), "{text:?} {doc:?}");
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Equalizer {
+	#[control(sliders(0.0..=1.0, vertical, step = 0.25, labels = ["Low", "Mid"]))]
+	pub bands: [f32; 3],
+}
+
+#[test]
+fn every_element_gets_a_labelled_slider() {
+	let mut equalizer = Equalizer { bands: [0.5; 3] };
+	let output = common::run_frames(vec![Default::default()], |ui| equalizer.show(ui));
+	let text = common::rendered_text(&output);
+
+	assert!(text.contains(&"Low".to_string()), "{text:?}");
+	assert!(text.contains(&"Mid".to_string()), "{text:?}");
+	// More sliders than labels fall back to the index.
+	assert!(text.contains(&"2".to_string()), "{text:?}");
+	let (low, mid) = (
+		common::text_rect(&output, "Low"),
+		common::text_rect(&output, "Mid"),
+	);
+	assert!(mid.left() > low.right(), "{low:?} {mid:?}");
+}
+
+#[test]
+fn sliders_edit_their_own_element() {
+	let mut equalizer = Equalizer { bands: [0.5; 3] };
+	let output = common::run_frames(vec![Default::default()], |ui| equalizer.show(ui));
+	let mid = common::text_rect(&output, "Mid");
+	// The bottom end of the vertical slider above the label.
+	let bottom = egui::pos2(mid.center().x, mid.top() - 30.0);
+	common::run_frames(common::click(bottom), |ui| equalizer.show(ui));
+
+	assert_eq!(equalizer.bands[0], 0.5);
+	assert!(equalizer.bands[1] < 0.5, "{:?}", equalizer.bands);
+	assert_eq!(equalizer.bands[2], 0.5);
+}
@@ -0,0 +1,51 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(summary)]
+pub struct Movement {
+	#[control(slider(0.0..=10.0))]
+	pub move_speed: f32,
+	#[control]
+	pub vsync: bool,
+	#[control(button)]
+	pub reset: bool,
+	pub internal: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Default, ControlPanel)]
+pub enum Mode {
+	#[default]
+	Walk,
+	Fly,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(summary(debug), labels_from_serde)]
+pub struct Player {
+	#[control]
+	pub name: String,
+	#[control]
+	pub mode: Mode,
+}
+
+#[test]
+fn the_summary_lists_every_control() {
+	let movement = Movement {
+		move_speed: 5.5,
+		vsync: true,
+		reset: false,
+		internal: 7,
+	};
+
+	assert_eq!(movement.summary(), "Move speed: 5.5, Vsync: true");
+}
+
+#[test]
+fn the_summary_can_use_debug() {
+	let player = Player {
+		name: "ferris".to_string(),
+		mode: Mode::Fly,
+	};
+
+	assert_eq!(player.summary(), r#"Name: "ferris", Mode: Fly"#);
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Profile {
+	#[control(textbox(max_len = 4, width = 120.0))]
+	pub tag: String,
+}
+
+/// The frames focusing the text edit at `pos` and typing `text` into it.
+fn typing(pos: egui::Pos2, text: &str) -> Vec<egui::RawInput> {
+	let mut inputs = common::click(pos);
+	inputs.push(egui::RawInput {
+		events: vec![egui::Event::Text(text.to_string())],
+		..Default::default()
+	});
+	inputs
+}
+
+#[test]
+fn edits_are_truncated_at_char_boundaries() {
+	let mut profile = Profile {
+		tag: "ab".to_string(),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| profile.show(ui));
+	let text_edit = common::text_rect(&output, "ab").right_center() + egui::vec2(20.0, 0.0);
+	let output = common::run_frames(typing(text_edit, "çé€"), |ui| profile.show(ui));
+
+	assert_eq!(profile.tag, "abçé");
+	assert!(common::rendered_text(&output).contains(&"4/4".to_string()));
+}
+
+#[test]
+fn the_width_is_applied() {
+	let mut profile = Profile::default();
+	let output = common::run_frames(vec![Default::default()], |ui| profile.show(ui));
+	let count = common::text_rect(&output, "0/4");
+
+	// The count comes right after the text edit, margins included.
+	assert!(count.left() > 120.0 && count.left() < 150.0, "{count:?}");
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Overlay {
+	/// Draw the colliders of every entity.
+	#[control(bool(wide))]
+	pub show_colliders: bool,
+	#[control(bool(wide))]
+	pub show_paths: bool,
+}
+
+#[test]
+fn clicking_anywhere_on_the_text_toggles() {
+	let mut overlay = Overlay::default();
+	let output = common::run_frames(vec![Default::default()], |ui| overlay.show(ui));
+	let text = common::rendered_text(&output);
+	assert_eq!(text, ["Draw the colliders of every entity.", "Show paths"]);
+
+	// The right end of the text is far from where a checkbox would be.
+	let colliders = common::text_rect(&output, "Draw the colliders of every entity.");
+	let right_end = colliders.right_center() - egui::vec2(5.0, 0.0);
+	common::run_frames(common::click(right_end), |ui| overlay.show(ui));
+	assert!(overlay.show_colliders);
+	assert!(!overlay.show_paths);
+
+	common::run_frames(common::click(right_end), |ui| overlay.show(ui));
+	assert!(!overlay.show_colliders);
+}
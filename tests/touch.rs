@@ -0,0 +1,53 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Inner {
+	#[control(bool)]
+	pub enabled: bool,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Desktop {
+	#[control(bool)]
+	pub enabled: bool,
+	#[control(nested)]
+	pub inner: Inner,
+}
+
+#[derive(Debug, Default, ControlPanel)]
+#[controls(touch)]
+pub struct Touch {
+	#[control(bool)]
+	pub enabled: bool,
+	#[control(nested)]
+	pub inner: Inner,
+}
+
+/// The size of the panel, and the interact size of the `Ui` it was shown in afterwards.
+fn measure(mut show: impl FnMut(&mut egui::Ui) -> egui::Response) -> (egui::Vec2, egui::Vec2) {
+	let mut measured = None;
+	common::run_frames(vec![Default::default()], |ui| {
+		let size = show(ui).rect.size();
+		measured = Some((size, ui.spacing().interact_size));
+	});
+	measured.unwrap()
+}
+
+#[test]
+fn touch_panels_are_larger() {
+	let (desktop, _) = measure(|ui| Desktop::default().ui(ui));
+	let (touch, _) = measure(|ui| Touch::default().ui(ui));
+
+	assert!(touch.y > desktop.y * 1.5, "{touch:?} vs {desktop:?}");
+}
+
+#[test]
+fn the_spacing_is_restored_after_the_panel() {
+	let (_, desktop) = measure(|ui| Desktop::default().ui(ui));
+	let (_, touch) = measure(|ui| Touch::default().ui(ui));
+
+	assert_eq!(touch, desktop);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Clone, PartialEq, Default, ControlPanel)]
+#[controls(track_dirty)]
+pub struct Audio {
+	/// Whether sound is muted.
+	#[control(bool)]
+	pub muted: bool,
+	#[control(slider(0.0..=1.0))]
+	pub volume: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, strum::EnumIter, ControlPanel)]
+#[controls(track_dirty)]
+pub enum Output {
+	#[default]
+	Speakers,
+	Headphones,
+}
+
+#[test]
+fn external_mutation_is_detected() {
+	let mut audio = Audio::default();
+	let mut snapshot = None;
+	assert!(!audio.take_dirty(&mut snapshot));
+	assert!(!audio.take_dirty(&mut snapshot));
+
+	audio.volume = 0.5;
+	assert!(audio.take_dirty(&mut snapshot));
+	assert!(!audio.take_dirty(&mut snapshot));
+
+	// Changed and changed back in between calls is no change at all.
+	audio.muted = true;
+	audio.muted = false;
+	assert!(!audio.take_dirty(&mut snapshot));
+}
+
+#[test]
+fn edits_through_the_panel_are_detected() {
+	let mut audio = Audio::default();
+	let mut snapshot = None;
+	audio.take_dirty(&mut snapshot);
+	let output = common::run_frames(vec![Default::default()], |ui| audio.show(ui));
+	assert!(!audio.take_dirty(&mut snapshot));
+
+	let checkbox = common::left_of(&output, "Whether sound is muted.");
+	common::run_frames(common::click(checkbox), |ui| audio.show(ui));
+	assert!(audio.muted);
+	assert!(audio.take_dirty(&mut snapshot));
+}
+
+#[test]
+fn values_are_tracked_separately() {
+	let speakers = Output::Speakers;
+	let mut headphones = Output::Speakers;
+	let (mut speakers_snapshot, mut headphones_snapshot) = (None, None);
+	speakers.take_dirty(&mut speakers_snapshot);
+	headphones.take_dirty(&mut headphones_snapshot);
+
+	headphones = Output::Headphones;
+	assert!(!speakers.take_dirty(&mut speakers_snapshot));
+	assert!(headphones.take_dirty(&mut headphones_snapshot));
+}
+
+#[test]
+fn a_fresh_value_in_the_same_place_starts_clean() {
+	let mut slot = Audio {
+		muted: true,
+		volume: 0.5,
+	};
+	let mut snapshot = None;
+	slot.take_dirty(&mut snapshot);
+	let address = &slot as *const Audio;
+
+	// The old value is dropped and a different one shown at the same address.
+	slot = Audio::default();
+	let mut snapshot = None;
+	common::run_frames(vec![Default::default()], |ui| slot.show(ui));
+	assert_eq!(&slot as *const Audio, address);
+	assert!(!slot.take_dirty(&mut snapshot));
+
+	slot.volume = 0.25;
+	assert!(slot.take_dirty(&mut snapshot));
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Graphics {
+	#[control(tristate)]
+	pub vsync: Option<bool>,
+	#[control(tristate(none = "Inherit", on = "Yes", off = "No"))]
+	pub shadows: Option<bool>,
+}
+
+#[test]
+fn clicking_cycles_through_all_three_states() {
+	let mut graphics = Graphics::default();
+	let output = common::run_frames(vec![Default::default()], |ui| graphics.show(ui));
+	let on = common::text_center(&output, "On");
+	let off = common::text_center(&output, "Off");
+	let default = common::text_center(&output, "Default");
+
+	let mut changed = vec![];
+	common::run_frames(common::click(on), |ui| {
+		changed.push(graphics.ui_changed(ui))
+	});
+	assert_eq!(graphics.vsync, Some(true));
+	common::run_frames(common::click(off), |ui| {
+		changed.push(graphics.ui_changed(ui))
+	});
+	assert_eq!(graphics.vsync, Some(false));
+	common::run_frames(common::click(default), |ui| {
+		changed.push(graphics.ui_changed(ui))
+	});
+	assert_eq!(graphics.vsync, None);
+
+	assert_eq!(changed.iter().filter(|changed| **changed).count(), 3);
+	assert_eq!(graphics.shadows, None);
+}
+
+#[test]
+fn the_labels_can_be_overridden() {
+	let mut graphics = Graphics::default();
+	let output = common::run_frames(vec![Default::default()], |ui| graphics.show(ui));
+	let text = common::rendered_text(&output);
+	assert_eq!(text, ["Default", "On", "Off", "Inherit", "Yes", "No"]);
+
+	let yes = common::text_center(&output, "Yes");
+	common::run_frames(common::click(yes), |ui| graphics.show(ui));
+	assert_eq!(graphics.shadows, Some(true));
+}
@@ -0,0 +1,29 @@
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Volume(
+	/// The master volume.
+	#[control(slider(0.0..=1.0, fill))]
+	pub f32,
+	/// Whether the sound is muted.
+	#[control(bool)]
+	pub bool,
+	pub String,
+);
+
+#[test]
+fn tuple_struct_fields_are_accessed_by_index() {
+	let mut volume = Volume(0.75, false, "untouched".to_string());
+
+	let ctx = egui::Context::default();
+	let _ = ctx.run(egui::RawInput::default(), |ctx| {
+		egui::CentralPanel::default().show(ctx, |ui| {
+			volume.show(ui);
+		});
+	});
+
+	assert_eq!(volume.0, 0.75);
+	assert!(!volume.1);
+	assert_eq!(volume.2, "untouched");
+}
@@ -0,0 +1,11 @@
+//! The diagnostics of misused attributes, see the `.stderr` files next to each case in `tests/ui`.
+//!
+//! The snapshots name emitted items by their `bevy_egui::egui` paths, which the `egui` feature
+//! replaces with `::egui` ones, so they are only checked without it.
+#![cfg(not(feature = "egui"))]
+
+#[test]
+fn ui() {
+	let cases = trybuild::TestCases::new();
+	cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Reactor {
+	#[control(slider(0.0..=1.0), accent = "orange")]
+	pub temperature: f32,
+}
+
+fn main() {}
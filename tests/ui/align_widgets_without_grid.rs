@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(align_widgets = "right")]
+pub struct Camera {
+	#[control]
+	pub fov: f32,
+}
+
+fn main() {}
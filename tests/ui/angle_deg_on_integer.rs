@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Compass {
+	#[control(angle_deg(wrap))]
+	pub heading: i32,
+}
+
+fn main() {}
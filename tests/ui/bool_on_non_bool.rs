@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(bool)]
+	pub speed: f32,
+}
+
+fn main() {}
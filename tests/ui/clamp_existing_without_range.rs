@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Mixer {
+	#[control(drag(clamp_existing))]
+	pub volume: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(color)]
+	pub tint: [f32; 4],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Terrain {
+	#[control(slider(0.0..=1.0), debounce = 0.2)]
+	pub roughness: f32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bevy_egui_controls::ControlPanel;
+
+pub struct Meters(pub f32);
+
+#[derive(ControlPanel)]
+pub struct Stats {
+	#[control(display_via = "0")]
+	pub travelled: Meters,
+}
+
+fn main() {}
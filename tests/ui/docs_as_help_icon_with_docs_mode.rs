@@ -0,0 +1,11 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(docs = "hidden", docs_as_help_icon)]
+pub struct Settings {
+	/// Whether the game is paused.
+	#[control]
+	pub paused: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(slider(0.0..=1.0))]
+	#[control(fill)]
+	pub volume: f32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub enum Never {}
+
+fn main() {}
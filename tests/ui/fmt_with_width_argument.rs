@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Stats {
+	#[control(readonly, fmt = "{:>1$}")]
+	pub frame_time: f64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Item {
+	pub uuid: u64,
+	#[control(textbox, id_from = "id")]
+	pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Mixer {
+	#[control(list_slider(0.0..=1.0))]
+	pub master: f32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel, strum::EnumIter)]
+pub enum Shape {
+	Circle {
+		#[control(slider(0.0..=1.0), on_change = "recompute")]
+		radius: f32,
+	},
+}
+
+impl Shape {
+	fn recompute(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Dashboard {
+	#[control(plot, on_change = "refresh")]
+	pub fps: Vec<f32>,
+}
+
+impl Dashboard {
+	fn refresh(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Dashboard {
+	#[control(plot, raw(.height(40.0)))]
+	pub fps: Vec<f32>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Layer {
+	#[control(slider(0.0..=1.0), raw(.opacity_text("opacity")))]
+	pub opacity: f32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(method = "render_settings")]
+pub struct Audio {
+	#[control]
+	pub music: bool,
+}
+
+fn render(audio: &mut Audio, ui: &mut egui::Ui) -> egui::Response {
+	audio.ui(ui)
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(reset_field)]
+pub struct Audio {
+	#[control(bool)]
+	pub muted: bool,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::time::Instant;
+
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Timers {
+	#[control(since)]
+	pub saved: Instant,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(skip, on_change = "recompute")]
+	pub level: u8,
+}
+
+impl Settings {
+	fn recompute(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(skip, slider(0..=1))]
+	pub level: u8,
+}
+
+fn main() {}
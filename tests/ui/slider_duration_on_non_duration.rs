@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Ability {
+	#[control(slider_duration(0.0..=60.0))]
+	pub cooldown: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(slider(0..=10))]
+	pub name: String,
+}
+
+fn main() {}
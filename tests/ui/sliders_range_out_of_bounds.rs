@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Mixer {
+	#[control(sliders(-1..=255))]
+	pub channels: [u8; 4],
+}
+
+fn main() {}
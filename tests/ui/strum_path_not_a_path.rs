@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(strum_path = "not a path")]
+pub enum Quality {
+	Low,
+	High,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(summary)]
+pub enum Mode {
+	Walk,
+	Fly,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(PartialEq, ControlPanel)]
+#[controls(track_dirty)]
+pub struct Audio {
+	#[control(bool)]
+	pub muted: bool,
+}
+
+fn main() {}
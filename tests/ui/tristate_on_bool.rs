@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Graphics {
+	#[control(tristate)]
+	pub vsync: bool,
+}
+
+fn main() {}
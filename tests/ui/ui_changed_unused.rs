@@ -0,0 +1,16 @@
+#![deny(unused_must_use)]
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(bool)]
+	pub enabled: bool,
+}
+
+fn show(settings: &mut Settings, ui: &mut egui::Ui) {
+	settings.ui_changed(ui);
+}
+
+fn main() {}
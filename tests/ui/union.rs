@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub union Bits {
+	pub float: f32,
+	pub int: u32,
+}
+
+fn main() {}
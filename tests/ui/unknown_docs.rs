@@ -0,0 +1,11 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+#[controls(docs = "tooltip")]
+pub struct Settings {
+	/// How loud it is.
+	#[control(slider(0.0..=1.0))]
+	pub volume: f32,
+}
+
+fn main() {}
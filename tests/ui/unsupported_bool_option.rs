@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Debug {
+	#[control(bool(narrow))]
+	pub show_colliders: bool,
+}
+
+fn main() {}
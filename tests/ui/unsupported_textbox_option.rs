@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Profile {
+	#[control(textbox(multiline))]
+	pub bio: String,
+}
+
+fn main() {}
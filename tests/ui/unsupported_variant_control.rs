@@ -0,0 +1,10 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub enum State {
+	Idle,
+	#[control(bool)]
+	Running,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_egui_controls::ControlPanel;
+
+#[derive(ControlPanel)]
+pub struct Settings {
+	#[control(with = "|value, ui| ui.label(value)")]
+	pub name: String,
+}
+
+fn main() {}
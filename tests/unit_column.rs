@@ -0,0 +1,70 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+/// The frames rendering a grid, which is only painted once it knows its column widths.
+fn frames() -> Vec<egui::RawInput> {
+	vec![Default::default(), Default::default()]
+}
+
+#[derive(Debug, ControlPanel)]
+#[controls(unit_column)]
+pub struct Projectile {
+	/// Muzzle speed.
+	#[control(slider(0.0..=500.0), unit = "m/s")]
+	pub speed: f32,
+	/// Number of bounces.
+	#[control(slider(0..=10))]
+	pub bounces: u32,
+	/// Drop per second.
+	#[control(slider(0.0..=50.0), unit = "m/s²")]
+	pub gravity: f32,
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Inline {
+	/// Muzzle speed.
+	#[control(slider(0.0..=500.0), unit = "m/s")]
+	pub speed: f32,
+}
+
+#[test]
+fn units_and_descriptions_get_a_column_each() {
+	let mut projectile = Projectile {
+		speed: 120.0,
+		bounces: 2,
+		gravity: 9.8,
+	};
+	let output = common::run_frames(frames(), |ui| projectile.show(ui));
+	let speed_unit = common::text_rect(&output, "m/s");
+	let gravity_unit = common::text_rect(&output, "m/s²");
+	let speed = common::text_rect(&output, "Muzzle speed.");
+	let bounces = common::text_rect(&output, "Number of bounces.");
+	let gravity = common::text_rect(&output, "Drop per second.");
+
+	// The glyphs start a little into their text, so the edges differ by less than a pixel or two.
+	let aligned = |a: egui::Rect, b: egui::Rect| (a.left() - b.left()).abs() < 2.0;
+	assert!(
+		aligned(speed_unit, gravity_unit),
+		"{speed_unit:?} {gravity_unit:?}"
+	);
+	assert!(
+		speed.left() > gravity_unit.right(),
+		"{speed:?} {gravity_unit:?}"
+	);
+	assert!(aligned(speed, bounces), "{speed:?} {bounces:?}");
+	assert!(aligned(speed, gravity), "{speed:?} {gravity:?}");
+}
+
+#[test]
+fn units_follow_the_widget_outside_of_a_unit_column() {
+	let mut inline = Inline { speed: 120.0 };
+	let output = common::run_frames(vec![Default::default()], |ui| inline.show(ui));
+	let value = common::text_rect(&output, "120");
+	let unit = common::text_rect(&output, "m/s");
+	let doc = common::text_rect(&output, "Muzzle speed.");
+
+	assert!(value.right() < unit.left(), "{value:?} {unit:?}");
+	assert!(unit.right() < doc.left(), "{unit:?} {doc:?}");
+}
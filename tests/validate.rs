@@ -0,0 +1,57 @@
+mod common;
+
+use bevy_egui_controls::ControlPanel;
+
+fn not_empty(value: &str) -> Result<(), String> {
+	match value.is_empty() {
+		true => Err("cannot be empty".to_string()),
+		false => Ok(()),
+	}
+}
+
+fn at_most_ten(value: &u32) -> Result<(), String> {
+	match *value <= 10 {
+		true => Ok(()),
+		false => Err(format!("{value} is more than 10")),
+	}
+}
+
+#[derive(Debug, ControlPanel)]
+pub struct Profile {
+	/// Display name.
+	#[control(textbox, validate = "not_empty")]
+	pub name: String,
+	/// Party size.
+	#[control(validate = "at_most_ten")]
+	pub party: u32,
+}
+
+#[test]
+fn validation_errors_lists_the_failing_fields() {
+	let profile = Profile {
+		name: String::new(),
+		party: 4,
+	};
+
+	assert_eq!(
+		profile.validation_errors(),
+		[("name", "cannot be empty".to_string())]
+	);
+}
+
+#[test]
+fn errors_are_rendered_after_the_widget() {
+	let mut profile = Profile {
+		name: "Ada".to_string(),
+		party: 12,
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| profile.show(ui));
+	let error = common::text_rect(&output, "12 is more than 10");
+	let value = common::text_rect(&output, "12");
+	let doc = common::text_rect(&output, "Party size.");
+	let text = common::rendered_text(&output);
+
+	assert!(value.right() < error.left(), "{value:?} {error:?}");
+	assert!(error.right() < doc.left(), "{error:?} {doc:?}");
+	assert!(!text.contains(&"cannot be empty".to_string()), "{text:?}");
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Counter(u32);
+
+mod widgets {
+	use super::*;
+
+	pub fn counter(counter: &mut Counter, ui: &mut egui::Ui) -> egui::Response {
+		let mut response = ui.button(format!("clicked {} times", counter.0));
+		if response.clicked() {
+			counter.0 += 1;
+			response.mark_changed();
+		}
+		response
+	}
+}
+
+#[derive(Debug, Default, ControlPanel)]
+pub struct Settings {
+	#[control(with = "widgets::counter")]
+	pub clicks: Counter,
+}
+
+#[test]
+fn fields_render_through_the_given_function() {
+	let mut settings = Settings::default();
+	let output = common::run_frames(vec![Default::default()], |ui| settings.show(ui));
+	let button = common::text_center(&output, "clicked 0 times");
+	common::run_frames(common::click(button), |ui| settings.show(ui));
+
+	assert_eq!(settings.clicks, Counter(1));
+}
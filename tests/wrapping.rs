@@ -0,0 +1,34 @@
+mod common;
+
+use std::num::Wrapping;
+
+use bevy_egui::egui;
+use bevy_egui_controls::ControlPanel;
+
+#[derive(Debug, ControlPanel)]
+pub struct Counters {
+	/// Frames rendered.
+	#[control(slider(0..=100))]
+	pub frames: Wrapping<u32>,
+	/// Seed.
+	#[control]
+	pub seed: Wrapping<u8>,
+}
+
+/// The left end of the slider in the top left corner.
+const LEFT_END: egui::Pos2 = egui::pos2(9.0, 15.0);
+
+#[test]
+fn wrapping_numbers_are_edited_through_the_number_inside() {
+	let mut counters = Counters {
+		frames: Wrapping(40),
+		seed: Wrapping(7),
+	};
+	let output = common::run_frames(vec![Default::default()], |ui| counters.show(ui));
+	let text = common::rendered_text(&output);
+	assert!(text.contains(&"40".to_string()), "{text:?}");
+	assert!(text.contains(&"7".to_string()), "{text:?}");
+
+	common::run_frames(common::click(LEFT_END), |ui| counters.show(ui));
+	assert_eq!(counters.frames, Wrapping(0));
+}